@@ -0,0 +1,835 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use aoc_common::{AnswerError, InputError, IntoAnswer, ParseError, Solution, Streaming, input};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+}
+
+/// One line of the document, e.g. `L68`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rotation {
+    pub dir: Direction,
+    pub dist: i64,
+}
+
+/// The rotation as a line of the document.
+impl fmt::Display for Rotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let dir = match self.dir {
+            Direction::Left => 'L',
+            Direction::Right => 'R',
+        };
+        write!(f, "{}{}", dir, self.dist)
+    }
+}
+
+/// Parses one line of the document; blank lines yield `None`.
+fn parse_line(line_no: usize, line: &str) -> Result<Option<Rotation>, ParseError> {
+    let s = line.trim();
+    if s.is_empty() {
+        return Ok(None);
+    }
+
+    let (dir_str, dist_str) = s.split_at(s.chars().next().map_or(0, char::len_utf8));
+    let dir = match dir_str {
+        "R" | "r" => Direction::Right,
+        "L" | "l" => Direction::Left,
+        _ => {
+            let msg = format!("invalid direction '{}', expected L or R", dir_str);
+            return Err(ParseError::at(Day01::DAY, line_no, line, dir_str, msg));
+        }
+    };
+
+    let dist_str = dist_str.trim();
+    if dist_str.is_empty() {
+        return Err(ParseError::at_end(
+            Day01::DAY,
+            line_no,
+            line,
+            "missing distance",
+        ));
+    }
+    let dist = dist_str
+        .parse::<i64>()
+        .ok()
+        .filter(|&d| d >= 0)
+        .ok_or_else(|| {
+            let msg = format!("invalid distance '{}'", dist_str);
+            ParseError::at(Day01::DAY, line_no, line, dist_str, msg)
+        })?;
+
+    Ok(Some(Rotation { dir, dist }))
+}
+
+/// A well-formed line in plain ASCII, parsed straight from its bytes: `Some(None)` if it is
+/// blank, `None` if it needs [`parse_line`] (non-ASCII whitespace, a `+` sign, an error).
+fn parse_bytes(line: &[u8]) -> Option<Option<Rotation>> {
+    let Some((&dir, digits)) = line.trim_ascii().split_first() else {
+        return Some(None);
+    };
+    let dir = match dir {
+        b'R' | b'r' => Direction::Right,
+        b'L' | b'l' => Direction::Left,
+        _ => return None,
+    };
+    let digits = digits.trim_ascii_start();
+    if digits.is_empty() {
+        return None;
+    }
+    let mut dist: i64 = 0;
+    for &b in digits {
+        if !b.is_ascii_digit() {
+            return None;
+        }
+        dist = dist.checked_mul(10)?.checked_add(i64::from(b - b'0'))?;
+    }
+    Some(Some(Rotation { dir, dist }))
+}
+
+/// Parses line `line_no` without its `\n`, falling back to [`parse_line`] for anything the
+/// byte-level parser does not handle, so both accept and reject exactly the same lines. Like
+/// [`input::for_each_line`], a `\r` is only part of the terminator if a `\n` follows it.
+fn scan_line(
+    line_no: usize,
+    line: &[u8],
+    terminated: bool,
+    f: &mut impl FnMut(Rotation),
+) -> Result<(), InputError> {
+    let rotation = match parse_bytes(line) {
+        Some(rotation) => rotation,
+        None => {
+            let line = match line.strip_suffix(b"\r") {
+                Some(line) if terminated => line,
+                _ => line,
+            };
+            let text = std::str::from_utf8(line)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            parse_line(line_no, text)?
+        }
+    };
+    if let Some(r) = rotation {
+        f(r);
+    }
+    Ok(())
+}
+
+/// Calls `f` with every rotation in `reader`, in a single pass over its buffered bytes. Lines
+/// are parsed where they lie in the reader's buffer; only a line split between two reads is
+/// copied, into one buffer reused for the whole input.
+pub fn scan(reader: &mut dyn BufRead, mut f: impl FnMut(Rotation)) -> Result<(), InputError> {
+    let mut carry = Vec::new();
+    let mut line_no = 0;
+    loop {
+        let buf = match reader.fill_buf() {
+            Ok([]) => break,
+            Ok(buf) => buf,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut start = 0;
+        while let Some(len) = buf[start..].iter().position(|&b| b == b'\n') {
+            let line = &buf[start..start + len];
+            line_no += 1;
+            if carry.is_empty() {
+                scan_line(line_no, line, true, &mut f)?;
+            } else {
+                carry.extend_from_slice(line);
+                scan_line(line_no, &carry, true, &mut f)?;
+                carry.clear();
+            }
+            start += len + 1;
+        }
+        carry.extend_from_slice(&buf[start..]);
+        let read = buf.len();
+        reader.consume(read);
+    }
+    if !carry.is_empty() {
+        scan_line(line_no + 1, &carry, false, &mut f)?;
+    }
+    Ok(())
+}
+
+/// Day 1's [`input::StreamFn`]: both answers from one [`scan`] of the input, turning the
+/// dial as each line is read.
+pub fn stream_bytes(
+    reader: &mut dyn BufRead,
+) -> Result<[Result<String, AnswerError>; 2], InputError> {
+    let mut dial = Dial::default();
+    scan(reader, |r| {
+        dial.turn(r);
+    })?;
+    Ok(Day01::finish(dial).map(IntoAnswer::into_answer))
+}
+
+/// Which clicks a [`Dial`] counts as hits on its watched positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counting {
+    /// Only rotations that end on a watched position (part 1).
+    FinalPosition,
+    /// Every click that lands on a watched position, including those in the middle of a
+    /// rotation (part 2).
+    EveryClick,
+}
+
+/// A dial with positions `0..size`, where it points and how often it has hit one of its
+/// watched positions so far; everything a run over the rotations needs to remember. The
+/// puzzle's dial has 100 positions, starts at 50 and watches 0.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dial {
+    size: i64,
+    /// Sorted and without duplicates.
+    watched: Vec<i64>,
+    pos: i64,
+    /// Rotations that ended on a watched position.
+    pub stops: i64,
+    /// Clicks that landed on a watched position.
+    pub clicks: i64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Dial::new(100, 50, &[0])
+    }
+}
+
+impl Dial {
+    /// A dial of `size` positions pointing at `start` that counts hits on `watched`.
+    pub fn new(size: i64, start: i64, watched: &[i64]) -> Self {
+        assert!(size > 0, "a dial needs at least one position, got {}", size);
+        for &p in watched.iter().chain([&start]) {
+            assert!(
+                (0..size).contains(&p),
+                "position {} is not on a dial of size {}",
+                p,
+                size
+            );
+        }
+        let mut watched = watched.to_vec();
+        watched.sort_unstable();
+        watched.dedup();
+        Dial {
+            size,
+            watched,
+            pos: start,
+            stops: 0,
+            clicks: 0,
+        }
+    }
+
+    pub fn size(&self) -> i64 {
+        self.size
+    }
+
+    pub fn watched(&self) -> &[i64] {
+        &self.watched
+    }
+
+    /// Where the dial points, in `0..size`.
+    pub fn pos(&self) -> i64 {
+        self.pos
+    }
+
+    /// The hits counted so far in the given mode.
+    pub fn count(&self, counting: Counting) -> i64 {
+        match counting {
+            Counting::FinalPosition => self.stops,
+            Counting::EveryClick => self.clicks,
+        }
+    }
+
+    /// How many clicks of rotation `r`, starting from the current position, land on a
+    /// watched position.
+    pub fn hits(&self, r: Rotation) -> i64 {
+        self.hits_split(r.dir, r.dist / self.size, r.dist % self.size)
+    }
+
+    /// [`Dial::hits`] of a rotation of `laps * size + rest` clicks; dividing once per
+    /// rotation rather than once per watched position keeps long runs fast.
+    fn hits_split(&self, dir: Direction, laps: i64, rest: i64) -> i64 {
+        self.watched
+            .iter()
+            .map(|&w| clicks_on(self.size, self.pos, dir, laps, rest, w))
+            .sum()
+    }
+
+    /// Turns the dial by `r` and returns what the rotation did.
+    pub fn turn(&mut self, r: Rotation) -> Turn {
+        let (laps, rest) = (r.dist / self.size, r.dist % self.size);
+        let start = self.pos;
+        let hits = self.hits_split(r.dir, laps, rest);
+        self.clicks += hits;
+
+        self.pos = match r.dir {
+            Direction::Right if start + rest >= self.size => start + rest - self.size,
+            Direction::Right => start + rest,
+            Direction::Left if start < rest => start - rest + self.size,
+            Direction::Left => start - rest,
+        };
+        if self.watched.binary_search(&self.pos).is_ok() {
+            self.stops += 1;
+        }
+        aoc_common::trace!(
+            1,
+            "turn",
+            dir = match r.dir {
+                Direction::Right => "R",
+                Direction::Left => "L",
+            },
+            dist = r.dist,
+            pos = self.pos,
+            hits = hits,
+            stops = self.stops,
+            clicks = self.clicks,
+        );
+
+        Turn {
+            start,
+            end: self.pos,
+            dir: r.dir,
+            dist: r.dist,
+            size: self.size,
+            hits,
+        }
+    }
+
+    /// Turns the dial by each of `rotations` as the iterator is advanced, yielding what each
+    /// rotation did; the dial keeps its counts, so it can be read once the iterator is done.
+    pub fn turns<I>(&mut self, rotations: I) -> Turns<'_, I::IntoIter>
+    where
+        I: IntoIterator<Item = Rotation>,
+    {
+        Turns {
+            dial: self,
+            rotations: rotations.into_iter(),
+        }
+    }
+}
+
+/// How many of the `laps * size + rest` clicks turning a dial of `size` positions from
+/// `start` land on `target`; both positions are on the dial and `rest < size`.
+fn clicks_on(size: i64, start: i64, dir: Direction, laps: i64, rest: i64, target: i64) -> i64 {
+    // The first click landing on `target`, in 1..=size, recurs once per lap.
+    let gap = match dir {
+        Direction::Right => target - start,
+        Direction::Left => start - target,
+    };
+    let first = if gap > 0 { gap } else { gap + size };
+    laps + i64::from(rest >= first)
+}
+
+/// What one rotation did to a [`Dial`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Turn {
+    pub start: i64,
+    pub end: i64,
+    pub dir: Direction,
+    pub dist: i64,
+    /// The number of positions on the dial.
+    pub size: i64,
+    /// Clicks that landed on a watched position.
+    pub hits: i64,
+}
+
+impl Turn {
+    /// Complete revolutions of the dial during the rotation.
+    pub fn laps(&self) -> i64 {
+        self.dist / self.size
+    }
+
+    /// How many clicks of the rotation landed on `pos`, watched or not; summed over all
+    /// rotations this is how long the dial rested on each position.
+    pub fn clicks_on(&self, pos: i64) -> i64 {
+        assert!(
+            (0..self.size).contains(&pos),
+            "position {} is not on a dial of size {}",
+            pos,
+            self.size
+        );
+        let (laps, rest) = (self.dist / self.size, self.dist % self.size);
+        clicks_on(self.size, self.start, self.dir, laps, rest, pos)
+    }
+}
+
+/// The iterator returned by [`Dial::turns`].
+pub struct Turns<'a, I> {
+    dial: &'a mut Dial,
+    rotations: I,
+}
+
+impl<I: Iterator<Item = Rotation>> Iterator for Turns<'_, I> {
+    type Item = Turn;
+
+    fn next(&mut self) -> Option<Turn> {
+        let r = self.rotations.next()?;
+        Some(self.dial.turn(r))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.rotations.size_hint()
+    }
+}
+
+/// Why [`synthesize`] found no rotations producing the requested counts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Unreachable {
+    /// Rotations ending on zero were requested without any click on zero, but the start is
+    /// not zero and the dial cannot get there without clicking on it.
+    NoClickToZero,
+    /// Clicks on zero were requested without a rotation ending on zero, but a one-position
+    /// dial ends every rotation on zero.
+    SinglePosition,
+    /// The shortest sequence needs more rotations than allowed.
+    TooFewRotations { needed: usize, allowed: usize },
+}
+
+impl fmt::Display for Unreachable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unreachable::NoClickToZero => {
+                f.write_str("a dial not starting at 0 must click on 0 to end a rotation there")
+            }
+            Unreachable::SinglePosition => {
+                f.write_str("a one-position dial ends every rotation on 0")
+            }
+            Unreachable::TooFewRotations { needed, allowed } => write!(
+                f,
+                "needs at least {} rotations, but at most {} are allowed",
+                needed, allowed
+            ),
+        }
+    }
+}
+
+impl Error for Unreachable {}
+
+/// The shortest list of rotations that turns a dial of `size` positions, starting at `start`,
+/// so that exactly `stops` rotations end on 0 (part 1) and exactly `clicks` clicks land on 0
+/// (part 2), using at most `max_rotations` rotations.
+///
+/// Each rotation ends on 0 at most once, so `stops` rotations are needed, or one when only
+/// clicks are requested, and they are always enough: every rotation but the first starts
+/// from 0, and any number of clicks can be spent turning whole laps. Rotations of distance 0
+/// are only used when there are fewer clicks than stops.
+pub fn synthesize(
+    size: i64,
+    start: i64,
+    stops: i64,
+    clicks: i64,
+    max_rotations: usize,
+) -> Result<Vec<Rotation>, Unreachable> {
+    assert!(size > 0, "a dial needs at least one position, got {}", size);
+    assert!(
+        (0..size).contains(&start),
+        "position {} is not on a dial of size {}",
+        start,
+        size
+    );
+    assert!(
+        stops >= 0 && clicks >= 0,
+        "counts must not be negative, got {} and {}",
+        stops,
+        clicks
+    );
+
+    let needed = match (stops, clicks) {
+        (0, 0) => 0,
+        (0, _) if size == 1 => return Err(Unreachable::SinglePosition),
+        (0, _) => 1,
+        (_, 0) if start != 0 => return Err(Unreachable::NoClickToZero),
+        _ => stops as usize,
+    };
+    if needed > max_rotations {
+        return Err(Unreachable::TooFewRotations {
+            needed,
+            allowed: max_rotations,
+        });
+    }
+
+    if stops == 0 {
+        if clicks == 0 {
+            return Ok(Vec::new());
+        }
+        // Turn right past 0 `clicks` times and stop one click beyond it.
+        let first = if start == 0 { size } else { size - start };
+        return Ok(vec![Rotation {
+            dir: Direction::Right,
+            dist: first + (clicks - 1) * size + 1,
+        }]);
+    }
+
+    // The first rotation needs a click if it starts off 0. One click each for the `spread`
+    // rotations after it, and the rest in the first.
+    let spread = (stops - 1).min((clicks - 1).max(0));
+    let mut rotations = Vec::with_capacity(needed);
+    let mut pos = start;
+    for i in 0..stops {
+        let hits = match i {
+            0 => clicks - spread,
+            _ if i <= spread => 1,
+            _ => 0,
+        };
+        let r = if pos == 0 {
+            Rotation {
+                dir: Direction::Right,
+                dist: hits * size,
+            }
+        } else if pos <= size - pos {
+            Rotation {
+                dir: Direction::Left,
+                dist: pos + (hits - 1) * size,
+            }
+        } else {
+            Rotation {
+                dir: Direction::Right,
+                dist: size - pos + (hits - 1) * size,
+            }
+        };
+        rotations.push(r);
+        pos = 0;
+    }
+    Ok(rotations)
+}
+
+fn run(rotations: &[Rotation]) -> Dial {
+    let mut dial = Dial::default();
+    dial.turns(rotations.iter().copied()).for_each(drop);
+    dial
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Vec<Rotation>;
+    type Answer = i64;
+
+    fn parse(input: &str) -> Result<Vec<Rotation>, ParseError> {
+        input::parse_str(input, Self::parse_reader)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Vec<Rotation>, InputError> {
+        let mut rotations = Vec::new();
+        scan(reader, |r| rotations.push(r))?;
+        Ok(rotations)
+    }
+
+    // Part 1: Count zero hits only at final positions
+    fn part_one(rotations: &Vec<Rotation>) -> i64 {
+        run(rotations).count(Counting::FinalPosition)
+    }
+
+    // Part 2: Count zero hits at ALL intermediate clicks
+    fn part_two(rotations: &Vec<Rotation>) -> i64 {
+        run(rotations).count(Counting::EveryClick)
+    }
+}
+
+impl Streaming for Day01 {
+    type State = Dial;
+
+    fn feed(dial: &mut Dial, line_no: usize, line: &str) -> Result<(), ParseError> {
+        if let Some(r) = parse_line(line_no, line)? {
+            dial.turn(r);
+        }
+        Ok(())
+    }
+
+    fn finish(dial: Dial) -> [i64; 2] {
+        [
+            dial.count(Counting::FinalPosition),
+            dial.count(Counting::EveryClick),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part_one(&input), 3);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day01::parse(EXAMPLE).unwrap();
+        assert_eq!(Day01::part_two(&input), 6);
+    }
+
+    #[test]
+    fn reports_each_rotation() {
+        let rotations = Day01::parse(EXAMPLE).unwrap();
+        let mut dial = Dial::default();
+        let turns: Vec<Turn> = dial.turns(rotations.iter().copied()).collect();
+
+        assert_eq!(turns.len(), rotations.len());
+        assert_eq!((turns[0].start, turns[0].end, turns[0].hits), (50, 82, 1));
+        assert!(turns.windows(2).all(|w| w[0].end == w[1].start));
+        assert_eq!(turns.iter().map(|t| t.hits).sum::<i64>(), dial.clicks);
+
+        let most = turns.iter().max_by_key(|t| t.hits).unwrap();
+        assert_eq!(most.hits, 1);
+
+        let lap = Dial::default().turn(Rotation {
+            dir: Direction::Left,
+            dist: 250,
+        });
+        assert_eq!((lap.end, lap.laps(), lap.hits), (0, 2, 3));
+        assert_eq!((lap.clicks_on(50), lap.clicks_on(1)), (2, 3));
+    }
+
+    #[test]
+    fn streams_example() {
+        let answers = input::stream::<Day01>(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(answers, [3, 6]);
+    }
+
+    /// Turns a dial one click at a time; returns (watched positions after a rotation, watched
+    /// positions on any click).
+    fn click_by_click(
+        size: i64,
+        start: i64,
+        watched: &[i64],
+        rotations: &[Rotation],
+    ) -> (i64, i64) {
+        let (mut pos, mut at_end, mut on_click) = (start, 0, 0);
+        for r in rotations {
+            let step = match r.dir {
+                Direction::Right => 1,
+                Direction::Left => size - 1,
+            };
+            for _ in 0..r.dist {
+                pos = (pos + step) % size;
+                if watched.contains(&pos) {
+                    on_click += 1;
+                }
+            }
+            if watched.contains(&pos) {
+                at_end += 1;
+            }
+        }
+        (at_end, on_click)
+    }
+
+    #[test]
+    fn matches_click_by_click_reference() {
+        testing::check(
+            "day 1",
+            2000,
+            |rng| {
+                (0..rng.usize(0, 20))
+                    .map(|_| Rotation {
+                        dir: *rng.pick(&[Direction::Left, Direction::Right]),
+                        // Favour multiples of 100 and distances ending near the zero mark.
+                        dist: match rng.range(0, 3) {
+                            0 => 100 * rng.range(0, 3),
+                            1 => 50 + 100 * rng.range(0, 2) + rng.range(0, 2) - 1,
+                            _ => rng.range(0, 350),
+                        } as i64,
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |rotations| {
+                let mut smaller = testing::shrink_vec(rotations);
+                smaller.extend(testing::shrink_each(rotations, |r| {
+                    testing::shrink_u64(r.dist as u64)
+                        .into_iter()
+                        .map(|dist| Rotation {
+                            dist: dist as i64,
+                            ..*r
+                        })
+                        .collect()
+                }));
+                smaller
+            },
+            |rotations| {
+                let (at_end, on_click) = click_by_click(100, 50, &[0], rotations);
+                testing::expect_eq(Day01::part_one(rotations), at_end)?;
+                testing::expect_eq(Day01::part_two(rotations), on_click)
+            },
+        );
+    }
+
+    #[test]
+    fn configured_dial_matches_click_by_click_reference() {
+        testing::check(
+            "day 1 dial",
+            2000,
+            |rng| {
+                let size = rng.range(1, 12) as i64;
+                let start = rng.range(0, size as u64 - 1) as i64;
+                let watched: Vec<i64> = (0..rng.usize(0, 3))
+                    .map(|_| rng.range(0, size as u64 - 1) as i64)
+                    .collect();
+                let rotations: Vec<Rotation> = (0..rng.usize(0, 10))
+                    .map(|_| Rotation {
+                        dir: *rng.pick(&[Direction::Left, Direction::Right]),
+                        dist: rng.range(0, 40) as i64,
+                    })
+                    .collect();
+                (size, start, watched, rotations)
+            },
+            |(size, start, watched, rotations)| {
+                testing::shrink_vec(rotations)
+                    .into_iter()
+                    .map(|smaller| (*size, *start, watched.clone(), smaller))
+                    .collect()
+            },
+            |(size, start, watched, rotations)| {
+                let mut dial = Dial::new(*size, *start, watched);
+                for &r in rotations {
+                    dial.turn(r);
+                }
+                let (at_end, on_click) = click_by_click(*size, *start, watched, rotations);
+                testing::expect_eq(dial.count(Counting::FinalPosition), at_end)?;
+                testing::expect_eq(dial.count(Counting::EveryClick), on_click)
+            },
+        );
+    }
+
+    /// The fewest rotations reaching the counts, found by trying every sequence of short
+    /// rotations; `None` if no sequence of at most `depth` rotations does.
+    fn fewest_rotations(
+        size: i64,
+        start: i64,
+        stops: i64,
+        clicks: i64,
+        depth: usize,
+    ) -> Option<usize> {
+        let mut frontier = vec![Dial::new(size, start, &[0])];
+        for len in 0..=depth {
+            if frontier
+                .iter()
+                .any(|d| d.stops == stops && d.clicks == clicks)
+            {
+                return Some(len);
+            }
+            let mut next = Vec::new();
+            for dial in &frontier {
+                for dir in [Direction::Left, Direction::Right] {
+                    for dist in 0..=(clicks + 1) * size {
+                        let mut dial = dial.clone();
+                        dial.turn(Rotation { dir, dist });
+                        if dial.stops <= stops && dial.clicks <= clicks && !next.contains(&dial) {
+                            next.push(dial);
+                        }
+                    }
+                }
+            }
+            frontier = next;
+        }
+        None
+    }
+
+    #[test]
+    fn synthesizes_the_shortest_rotations() {
+        for size in 1..=4 {
+            for start in 0..size {
+                for stops in 0..=3 {
+                    for clicks in 0..=4 {
+                        let case = format!(
+                            "size {} start {} stops {} clicks {}",
+                            size, start, stops, clicks
+                        );
+                        let fewest = fewest_rotations(size, start, stops, clicks, 4);
+                        match synthesize(size, start, stops, clicks, 10) {
+                            Ok(rotations) => {
+                                let mut dial = Dial::new(size, start, &[0]);
+                                dial.turns(rotations.iter().copied()).for_each(drop);
+                                assert_eq!((dial.stops, dial.clicks), (stops, clicks), "{}", case);
+                                assert_eq!(Some(rotations.len()), fewest, "{}", case);
+                            }
+                            Err(e) => assert_eq!(fewest, None, "{}: {}", case, e),
+                        }
+                    }
+                }
+            }
+        }
+
+        let rotations = synthesize(100, 50, 3, 6, 3).unwrap();
+        let text: Vec<String> = rotations.iter().map(Rotation::to_string).collect();
+        assert_eq!(text, ["L350", "R100", "R100"]);
+        assert_eq!(
+            synthesize(100, 50, 3, 6, 2),
+            Err(Unreachable::TooFewRotations {
+                needed: 3,
+                allowed: 2
+            })
+        );
+        assert_eq!(
+            synthesize(100, 50, 1, 0, 5),
+            Err(Unreachable::NoClickToZero)
+        );
+    }
+
+    /// Every rotation, or the first error, as the line-by-line `str` parser sees the input.
+    fn parse_lines(text: &[u8]) -> Result<Vec<Rotation>, String> {
+        let mut rotations = Vec::new();
+        input::for_each_line(&mut &text[..], |line_no, line| {
+            rotations.extend(parse_line(line_no, line)?);
+            Ok(())
+        })
+        .map_err(|e| e.to_string())?;
+        Ok(rotations)
+    }
+
+    #[test]
+    fn scans_bytes_like_the_line_parser() {
+        const PIECES: &[&str] = &[
+            "L",
+            "R",
+            "r",
+            "1",
+            "0",
+            "99",
+            " ",
+            "\t",
+            "\r",
+            "\n",
+            "\n",
+            "+",
+            "x",
+            "\u{a0}",
+            "9999999999999999999",
+        ];
+        testing::check(
+            "day 1 scan",
+            3000,
+            |rng| {
+                let text: String = (0..rng.usize(0, 16)).map(|_| *rng.pick(PIECES)).collect();
+                (text.into_bytes(), rng.usize(1, 8))
+            },
+            |(text, chunk)| {
+                testing::shrink_vec(text)
+                    .into_iter()
+                    .map(|smaller| (smaller, *chunk))
+                    .collect()
+            },
+            |(text, chunk)| {
+                let mut rotations = Vec::new();
+                let mut reader = io::BufReader::with_capacity(*chunk, &text[..]);
+                let scanned = scan(&mut reader, |r| rotations.push(r))
+                    .map(|()| rotations)
+                    .map_err(|e| e.to_string());
+                testing::expect_eq(scanned, parse_lines(text))
+            },
+        );
+
+        let err = scan(&mut &b"R5\n\xff\n"[..], |_| {}).unwrap_err();
+        assert!(matches!(err, InputError::Io(_)));
+        let answers = stream_bytes(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(answers, [Ok("3".to_string()), Ok("6".to_string())]);
+    }
+}
@@ -0,0 +1,1192 @@
+use std::fmt;
+
+use aoc_common::{BigUint, Natural, Overflow, ParseError, Solution};
+
+type Num = u128;
+
+/// The base that IDs are written in when looking for repeated blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Radix(u32);
+
+impl Radix {
+    pub const DECIMAL: Radix = Radix(10);
+
+    /// # Panics
+    ///
+    /// If `radix` is not in `2..=36`, like [`u128::from_str_radix`].
+    pub fn new(radix: u32) -> Self {
+        assert!(
+            (2..=36).contains(&radix),
+            "radix must be in 2..=36, got {}",
+            radix
+        );
+        Radix(radix)
+    }
+
+    pub fn get(self) -> u32 {
+        self.0
+    }
+
+    /// radix^exp, or None on overflow
+    fn pow_checked(self, exp: u32) -> Option<Num> {
+        (self.0 as Num).checked_pow(exp)
+    }
+
+    /// Digits in the largest `Num`; no ID can be longer.
+    fn max_digits(self) -> u32 {
+        Num::MAX.ilog(self.0 as Num) + 1
+    }
+}
+
+/// Something wrong with the ranges line. Malformed fragments make the input unusable; the
+/// solver works around the others.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Issue {
+    /// Not `A-B` with numeric bounds; the fragment is left out.
+    Malformed(String),
+    /// `A-B` with `A > B`, read as `B-A`.
+    Reversed { read_as: (Num, Num) },
+    /// Shares IDs with a range listed earlier in the line; the two are merged.
+    Overlap { with: (Num, Num) },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Issue::Malformed(message) => f.write_str(message),
+            Issue::Reversed { read_as: (a, b) } => {
+                write!(f, "range bounds are reversed; read as {}-{}", a, b)
+            }
+            Issue::Overlap { with: (a, b) } => {
+                write!(f, "range overlaps {}-{}; the two are merged", a, b)
+            }
+        }
+    }
+}
+
+/// An [`Issue`] with the `len` bytes at byte `offset` of the ranges line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub offset: usize,
+    pub len: usize,
+    pub issue: Issue,
+}
+
+impl Diagnostic {
+    pub fn is_error(&self) -> bool {
+        matches!(self.issue, Issue::Malformed(_))
+    }
+
+    /// Locates the diagnostic in `line`, the ranges line it was found in.
+    pub fn to_parse_error(&self, line: &str) -> ParseError {
+        let message = self.issue.to_string();
+        ParseError::at_byte(Day02::DAY, 1, line, self.offset, self.len, message)
+    }
+}
+
+/// The ranges of the input, normalized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ranges {
+    /// The well-formed ranges in input order, low bound first.
+    pub listed: Vec<(Num, Num)>,
+    /// The same IDs as sorted ranges that neither overlap nor touch.
+    pub merged: Vec<(Num, Num)>,
+    /// Everything that was wrong with the line, in order of position.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Reads a comma-separated line of ranges, keeping every well-formed one and reporting each
+/// malformed fragment, reversed range and overlap at its byte offset in `line`.
+pub fn normalize(line: &str) -> Ranges {
+    let mut listed = Vec::new();
+    let mut spans = Vec::new();
+    let mut diagnostics = Vec::new();
+    let mut start = 0;
+    for part in line.split(',') {
+        let offset = start + (part.len() - part.trim_start().len());
+        start += part.len() + 1;
+        let p = part.trim();
+        if p.is_empty() {
+            continue;
+        }
+        let mut malformed = |at: usize, len: usize, message: String| {
+            diagnostics.push(Diagnostic {
+                offset: at,
+                len,
+                issue: Issue::Malformed(message),
+            });
+        };
+        let Some((a_str, b_str)) = p.split_once('-') else {
+            malformed(
+                offset,
+                p.len(),
+                format!("malformed range '{}', expected A-B", p),
+            );
+            continue;
+        };
+        let mut bound = |s: &str, from: usize| {
+            let t = s.trim();
+            let parsed = t.parse::<Num>();
+            if parsed.is_err() {
+                let message = format!("invalid range bound '{}'", t);
+                if t.is_empty() {
+                    malformed(offset, p.len(), message);
+                } else {
+                    malformed(from + (s.len() - s.trim_start().len()), t.len(), message);
+                }
+            }
+            parsed.ok()
+        };
+        let (Some(a), Some(b)) = (bound(a_str, offset), bound(b_str, offset + a_str.len() + 1))
+        else {
+            continue;
+        };
+        if a > b {
+            diagnostics.push(Diagnostic {
+                offset,
+                len: p.len(),
+                issue: Issue::Reversed { read_as: (b, a) },
+            });
+        }
+        listed.push((a.min(b), a.max(b)));
+        spans.push((offset, p.len()));
+    }
+
+    // Sweep in order of the low bound, tracking the range that reaches furthest so far: any
+    // range starting before its end overlaps it. The later of the two in the line is flagged.
+    let mut order: Vec<usize> = (0..listed.len()).collect();
+    order.sort_unstable_by_key(|&i| listed[i]);
+    let mut reach: Option<usize> = None;
+    for i in order {
+        if let Some(j) = reach {
+            if listed[i].0 <= listed[j].1 {
+                let (later, earlier) = (i.max(j), i.min(j));
+                let (offset, len) = spans[later];
+                diagnostics.push(Diagnostic {
+                    offset,
+                    len,
+                    issue: Issue::Overlap {
+                        with: listed[earlier],
+                    },
+                });
+            }
+            if listed[i].1 > listed[j].1 {
+                reach = Some(i);
+            }
+        } else {
+            reach = Some(i);
+        }
+    }
+    diagnostics.sort_by_key(|d| d.offset);
+
+    Ranges {
+        merged: disjoint(&listed),
+        listed,
+        diagnostics,
+    }
+}
+
+/// Sorts the ranges and merges those that overlap or touch, so that no ID is in two of them.
+fn disjoint(ranges: &[(Num, Num)]) -> Vec<(Num, Num)> {
+    let mut sorted = ranges.to_vec();
+    sorted.sort_unstable();
+    let mut merged: Vec<(Num, Num)> = Vec::with_capacity(sorted.len());
+    for (a, b) in sorted {
+        match merged.last_mut() {
+            Some((_, end)) if a <= end.saturating_add(1) => *end = (*end).max(b),
+            _ => merged.push((a, b)),
+        }
+    }
+    merged
+}
+
+/// Splits a range at the powers of the radix, yielding `(digits, a, b)` pieces whose IDs all
+/// have the same number of digits.
+fn pieces((a, b): (Num, Num), radix: Radix) -> impl Iterator<Item = (u32, Num, Num)> {
+    (1..=radix.max_digits()).filter_map(move |d| {
+        let low = radix.pow_checked(d - 1)?;
+        let high = radix.pow_checked(d).map_or(Num::MAX, |p| p - 1);
+        let (a, b) = (a.max(low), b.min(high));
+        (a <= b).then_some((d, a, b))
+    })
+}
+
+/// The [`pieces`] of ranges that are already disjoint.
+fn by_digit_count(
+    disjoint: &[(Num, Num)],
+    radix: Radix,
+) -> impl Iterator<Item = (u32, Num, Num)> + '_ {
+    disjoint.iter().flat_map(move |&range| pieces(range, radix))
+}
+
+/// The digits of `id` in `radix`, most significant first; none for 0.
+fn digits(mut id: Num, radix: Radix) -> Vec<u32> {
+    let mut digits = Vec::new();
+    while id > 0 {
+        digits.push((id % radix.get() as Num) as u32);
+        id /= radix.get() as Num;
+    }
+    digits.reverse();
+    digits
+}
+
+fn from_digits(digits: &[u32], radix: Radix) -> Option<Num> {
+    digits.iter().try_fold(0, |n: Num, &d| {
+        n.checked_mul(radix.get() as Num)?.checked_add(d as Num)
+    })
+}
+
+/// compute ((R^(k*t) - 1) / (R^k - 1)) for radix R, summed as R^(k*(t-1)) + … + R^k + 1 so that
+/// it fits even when R^(k*t) does not
+fn compute_factor(radix: Radix, k: u32, t: u32) -> Option<Num> {
+    let radix_k = radix.pow_checked(k)?;
+    (0..t).try_fold(0, |f: Num, _| f.checked_mul(radix_k)?.checked_add(1))
+}
+
+fn s_interval_for_range(a: Num, b: Num, factor: Num, s_min: Num, s_max: Num) -> Option<(Num, Num)> {
+    let s_low = {
+        let q = a / factor;
+        if q * factor < a { q + 1 } else { q }
+    };
+    let s_high = b / factor;
+
+    let l = s_low.max(s_min);
+    let r = s_high.min(s_max);
+
+    if l > r { None } else { Some((l, r)) }
+}
+
+/// What is added up over the invalid IDs.
+#[derive(Debug, Clone, Copy)]
+enum Measure {
+    Count,
+    Sum,
+}
+
+/// The `d`-digit IDs in `[a, b]` that are a `k`-digit block repeated `d / k` times are `s × F`
+/// for the blocks `s` in `l..=r`; returns `(F, l, r)`, or `None` if there are none.
+fn blocks(a: Num, b: Num, radix: Radix, k: u32, d: u32) -> Option<(Num, Num, Num)> {
+    let factor = compute_factor(radix, k, d / k)?;
+    let s_min = radix.pow_checked(k - 1)?;
+    let s_max = radix.pow_checked(k)? - 1;
+    let (l, r) = s_interval_for_range(a, b, factor, s_min, s_max)?;
+    Some((factor, l, r))
+}
+
+/// Count or sum of the `d`-digit IDs in `[a, b]` that are a `k`-digit block repeated `d / k`
+/// times. Their sum is `F` times an arithmetic series: `F × (l + r) × (r - l + 1) / 2`.
+fn repeated_measure<N: Natural>(
+    a: Num,
+    b: Num,
+    radix: Radix,
+    k: u32,
+    d: u32,
+    measure: Measure,
+) -> Result<N, Overflow> {
+    let Some((factor, l, r)) = blocks(a, b, radix, k, d) else {
+        return Ok(N::zero());
+    };
+    let (sum, count) = (l + r, r - l + 1);
+    if let Measure::Count = measure {
+        return N::from_u128(count);
+    }
+    // Blocks have at most half as many digits as the largest `Num`, so neither term can
+    // overflow; halve whichever one is even.
+    let (sum, count) = if sum.is_multiple_of(2) {
+        (sum / 2, count)
+    } else {
+        (sum, count / 2)
+    };
+    N::from_u128(factor)?
+        .checked_mul(&N::from_u128(sum)?)?
+        .checked_mul(&N::from_u128(count)?)
+}
+
+fn twice_by<N: Natural>(
+    disjoint: &[(Num, Num)],
+    radix: Radix,
+    measure: Measure,
+) -> Result<N, Overflow> {
+    by_digit_count(disjoint, radix)
+        .filter(|&(d, _, _)| d.is_multiple_of(2))
+        .try_fold(N::zero(), |total, (d, a, b)| {
+            total.checked_add(&repeated_measure(a, b, radix, d / 2, d, measure)?)
+        })
+}
+
+/// An ID whose shortest block has `p` digits is also a repetition of every multiple of `p` that
+/// divides its length, so the sums per block length overlap. Taking the IDs of each length `d`
+/// by their shortest block instead, `P(p) = S(p) - Σ P(q)` over the proper divisors `q` of `p`
+/// (Möbius inversion over the divisors of `d`, one divisor at a time) counts every ID once. No
+/// intermediate exceeds the answer, so the fixed-width sum overflows only if the answer does.
+fn at_least_twice_by<N: Natural>(
+    disjoint: &[(Num, Num)],
+    radix: Radix,
+    measure: Measure,
+) -> Result<N, Overflow> {
+    let mut total = N::zero();
+    for (d, a, b) in by_digit_count(disjoint, radix) {
+        let blocks: Vec<u32> = (1..d).filter(|&k| d.is_multiple_of(k)).collect();
+        let mut primitive: Vec<N> = Vec::with_capacity(blocks.len());
+        for &p in &blocks {
+            let mut sum = repeated_measure::<N>(a, b, radix, p, d, measure)?;
+            for (&q, shorter) in blocks.iter().zip(&primitive) {
+                if p.is_multiple_of(q) {
+                    sum = sum
+                        .checked_sub(shorter)
+                        .expect("IDs with a shorter block are counted in S(p)");
+                }
+            }
+            total = total.checked_add(&sum)?;
+            primitive.push(sum);
+        }
+    }
+    Ok(total)
+}
+
+/// Sum of the IDs whose digits in `radix` are a block repeated exactly twice.
+pub fn repeated_twice<N: Natural>(ranges: &[(Num, Num)], radix: Radix) -> Result<N, Overflow> {
+    twice_by(&disjoint(ranges), radix, Measure::Sum)
+}
+
+/// Sum of the IDs whose digits in `radix` are a block repeated at least twice.
+pub fn repeated_at_least_twice<N: Natural>(
+    ranges: &[(Num, Num)],
+    radix: Radix,
+) -> Result<N, Overflow> {
+    at_least_twice_by(&disjoint(ranges), radix, Measure::Sum)
+}
+
+/// How many invalid IDs there are and what they add up to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tally<N> {
+    pub count: N,
+    pub sum: N,
+}
+
+/// What one input range contributes to each part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeTally<N> {
+    pub range: (Num, Num),
+    /// IDs that are a block repeated exactly twice (part one).
+    pub twice: Tally<N>,
+    /// IDs that are a block repeated at least twice (part two).
+    pub at_least_twice: Tally<N>,
+}
+
+/// Tallies every range on its own, in input order, so IDs in overlapping ranges count in each.
+pub fn tally_ranges<N: Natural>(
+    ranges: &[(Num, Num)],
+    radix: Radix,
+) -> Result<Vec<RangeTally<N>>, Overflow> {
+    ranges
+        .iter()
+        .map(|&range| {
+            let one = [range];
+            Ok(RangeTally {
+                range,
+                twice: Tally {
+                    count: twice_by(&one, radix, Measure::Count)?,
+                    sum: twice_by(&one, radix, Measure::Sum)?,
+                },
+                at_least_twice: Tally {
+                    count: at_least_twice_by(&one, radix, Measure::Count)?,
+                    sum: at_least_twice_by(&one, radix, Measure::Sum)?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// An invalid ID and the shortest block that repeats to make it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Repetition {
+    pub id: Num,
+    pub block: Num,
+    pub repeats: u32,
+}
+
+impl Repetition {
+    /// Whether the ID is also its first half repeated twice, which part one counts: the
+    /// shortest block then fits an even number of times.
+    pub fn is_twice(&self) -> bool {
+        self.repeats.is_multiple_of(2)
+    }
+}
+
+/// The IDs with one block length `k` in a digit length: `s × factor` for `s` in `next..=last`,
+/// skipping blocks that are themselves repetitions, whose IDs have a shorter block.
+#[derive(Debug)]
+struct Lane {
+    repeats: u32,
+    factor: Num,
+    next: Num,
+    last: Num,
+    /// `(R^q, F)` for each proper divisor `q` of `k`, where `s` has period `q` if
+    /// `s == (s mod R^q) × F`.
+    periods: Vec<(Num, Num)>,
+}
+
+impl Lane {
+    fn new(a: Num, b: Num, radix: Radix, k: u32, d: u32) -> Option<Self> {
+        let (factor, next, last) = blocks(a, b, radix, k, d)?;
+        let periods = (1..k)
+            .filter(|&q| k.is_multiple_of(q))
+            .map(|q| Some((radix.pow_checked(q)?, compute_factor(radix, q, k / q)?)))
+            .collect::<Option<_>>()?;
+        let mut lane = Lane {
+            repeats: d / k,
+            factor,
+            next,
+            last,
+            periods,
+        };
+        lane.skip_periodic();
+        Some(lane)
+    }
+
+    fn skip_periodic(&mut self) {
+        while self.next <= self.last
+            && self
+                .periods
+                .iter()
+                .any(|&(radix_q, f)| self.next == self.next % radix_q * f)
+        {
+            self.next += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<Num> {
+        (self.next <= self.last).then(|| self.next * self.factor)
+    }
+}
+
+/// The IDs in one range that are a block repeated at least twice, in ascending order. Each
+/// digit length is a merge of one [`Lane`] per block length, so only the IDs yielded are ever
+/// computed.
+#[derive(Debug)]
+pub struct InvalidIds {
+    radix: Radix,
+    range: (Num, Num),
+    digits: u32,
+    lanes: Vec<Lane>,
+}
+
+/// The invalid IDs in `range` written in `radix`, lazily and in ascending order.
+pub fn invalid_ids(range: (Num, Num), radix: Radix) -> InvalidIds {
+    InvalidIds {
+        radix,
+        range,
+        digits: 0,
+        lanes: Vec::new(),
+    }
+}
+
+impl InvalidIds {
+    /// Starts on the next digit length in the range, or returns false past its end.
+    fn next_length(&mut self) -> bool {
+        let (a, b) = self.range;
+        while self.digits < self.radix.max_digits() {
+            self.digits += 1;
+            let d = self.digits;
+            let low = self.radix.pow_checked(d - 1).unwrap_or(Num::MAX);
+            let high = self.radix.pow_checked(d).map_or(Num::MAX, |p| p - 1);
+            if low > b {
+                break;
+            }
+            let (a, b) = (a.max(low), b.min(high));
+            if a <= b {
+                let radix = self.radix;
+                self.lanes = (1..d)
+                    .filter(|&k| d.is_multiple_of(k))
+                    .filter_map(|k| Lane::new(a, b, radix, k, d))
+                    .collect();
+                return true;
+            }
+        }
+        self.digits = self.radix.max_digits();
+        self.lanes.clear();
+        false
+    }
+}
+
+impl Iterator for InvalidIds {
+    type Item = Repetition;
+
+    fn next(&mut self) -> Option<Repetition> {
+        loop {
+            // Every ID has one shortest block, so the lanes never yield the same ID.
+            let lane = self
+                .lanes
+                .iter_mut()
+                .filter_map(|lane| Some((lane.peek()?, lane)))
+                .min_by_key(|&(id, _)| id);
+            if let Some((id, lane)) = lane {
+                let repetition = Repetition {
+                    id,
+                    block: lane.next,
+                    repeats: lane.repeats,
+                };
+                lane.next += 1;
+                lane.skip_periodic();
+                return Some(repetition);
+            }
+            if !self.next_length() {
+                return None;
+            }
+        }
+    }
+}
+
+impl std::iter::FusedIterator for InvalidIds {}
+
+/// A shape of ID that inventory tooling may want to flag. Besides recognising a single ID, a
+/// pattern lists its matches in a range directly rather than by testing every integer in it.
+///
+/// IDs are positive, so 0 never matches.
+pub trait Pattern {
+    /// Whether `id`, written in `radix`, has this shape.
+    fn matches(&self, id: Num, radix: Radix) -> bool;
+
+    /// The matching IDs in `range`, lazily and in ascending order.
+    fn ids(&self, range: (Num, Num), radix: Radix) -> Box<dyn Iterator<Item = Num>>;
+}
+
+/// The IDs in any of the ranges that match `pattern`, each once and in ascending order.
+pub fn flag<'a>(
+    ranges: &[(Num, Num)],
+    radix: Radix,
+    pattern: &'a dyn Pattern,
+) -> impl Iterator<Item = Num> + 'a {
+    disjoint(ranges)
+        .into_iter()
+        .flat_map(move |range| pattern.ids(range, radix))
+}
+
+/// A block repeated exactly `n` times, like `777` or `121212` for `n = 3`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RepeatedExactly(u32);
+
+impl RepeatedExactly {
+    /// # Panics
+    ///
+    /// If `n` is less than 2.
+    pub fn new(n: u32) -> Self {
+        assert!(n >= 2, "a block must repeat at least twice, got {}", n);
+        RepeatedExactly(n)
+    }
+}
+
+impl Pattern for RepeatedExactly {
+    fn matches(&self, id: Num, radix: Radix) -> bool {
+        let digits = digits(id, radix);
+        let n = self.0 as usize;
+        !digits.is_empty()
+            && digits.len().is_multiple_of(n)
+            && digits == digits[..digits.len() / n].repeat(n)
+    }
+
+    /// One `d`-digit ID per block for every length `d` that `n` divides, ascending with the
+    /// block.
+    fn ids(&self, range: (Num, Num), radix: Radix) -> Box<dyn Iterator<Item = Num>> {
+        let n = self.0;
+        Box::new(
+            pieces(range, radix)
+                .filter(move |&(d, _, _)| d.is_multiple_of(n))
+                .flat_map(move |(d, a, b)| blocks(a, b, radix, d / n, d))
+                .flat_map(|(factor, l, r)| (l..=r).map(move |s| s * factor)),
+        )
+    }
+}
+
+/// A block repeated exactly twice, like `123123`: part one's invalid IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Twice;
+
+impl Pattern for Twice {
+    fn matches(&self, id: Num, radix: Radix) -> bool {
+        RepeatedExactly(2).matches(id, radix)
+    }
+
+    fn ids(&self, range: (Num, Num), radix: Radix) -> Box<dyn Iterator<Item = Num>> {
+        RepeatedExactly(2).ids(range, radix)
+    }
+}
+
+/// A block repeated two or more times, like `123123` or `1212`: part two's invalid IDs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtLeastTwice;
+
+impl Pattern for AtLeastTwice {
+    fn matches(&self, id: Num, radix: Radix) -> bool {
+        let digits = digits(id, radix);
+        (2..=digits.len())
+            .filter(|&n| digits.len().is_multiple_of(n))
+            .any(|n| digits == digits[..digits.len() / n].repeat(n))
+    }
+
+    fn ids(&self, range: (Num, Num), radix: Radix) -> Box<dyn Iterator<Item = Num>> {
+        Box::new(invalid_ids(range, radix).map(|r| r.id))
+    }
+}
+
+/// The same read forwards and backwards, like `12321`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palindrome;
+
+impl Pattern for Palindrome {
+    fn matches(&self, id: Num, radix: Radix) -> bool {
+        let digits = digits(id, radix);
+        !digits.is_empty() && digits.iter().eq(digits.iter().rev())
+    }
+
+    /// A `d`-digit palindrome is its first `ceil(d / 2)` digits, mirrored, and mirroring is
+    /// increasing in them; so only the prefixes of the range's ends are walked.
+    fn ids(&self, range: (Num, Num), radix: Radix) -> Box<dyn Iterator<Item = Num>> {
+        Box::new(pieces(range, radix).flat_map(move |(d, a, b)| {
+            let mirrored = d / 2;
+            let shift = radix
+                .pow_checked(mirrored)
+                .expect("half of a digit length fits");
+            let middle = radix.pow_checked(d % 2).expect("one digit fits");
+            (a / shift..=b / shift)
+                .filter_map(move |prefix| {
+                    let mut rest = prefix / middle;
+                    let mut tail: Num = 0;
+                    for _ in 0..mirrored {
+                        tail = tail * radix.get() as Num + rest % radix.get() as Num;
+                        rest /= radix.get() as Num;
+                    }
+                    (prefix * shift).checked_add(tail)
+                })
+                .skip_while(move |&id| id < a)
+                .take_while(move |&id| id <= b)
+        }))
+    }
+}
+
+/// Digits that never decrease from left to right, like `11359`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NonDecreasing;
+
+/// The smallest ID of at least `id` whose digits never decrease: the digits after the first
+/// drop are all raised to the one before it.
+fn next_non_decreasing(id: Num, radix: Radix) -> Option<Num> {
+    let mut digits = digits(id.max(1), radix);
+    if let Some(drop) = (1..digits.len()).find(|&i| digits[i] < digits[i - 1]) {
+        let fill = digits[drop - 1];
+        digits[drop..].fill(fill);
+    }
+    from_digits(&digits, radix)
+}
+
+impl Pattern for NonDecreasing {
+    fn matches(&self, id: Num, radix: Radix) -> bool {
+        id > 0 && digits(id, radix).is_sorted()
+    }
+
+    fn ids(&self, (a, b): (Num, Num), radix: Radix) -> Box<dyn Iterator<Item = Num>> {
+        Box::new(
+            std::iter::successors(next_non_decreasing(a, radix), move |&id| {
+                next_non_decreasing(id.checked_add(1)?, radix)
+            })
+            .take_while(move |&id| id <= b),
+        )
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    // 2: ranges are normalized up front, which changes the answers for reversed ranges.
+    const VERSION: &'static str = "2";
+    type Input = Ranges;
+    type Answer = Result<Num, Overflow>;
+
+    /// The input is a single comma-separated line of ranges. Reversed and overlapping ranges
+    /// are only diagnosed, but a malformed fragment is an error.
+    fn parse(input: &str) -> Result<Ranges, ParseError> {
+        let line = input.lines().next().unwrap_or("");
+        let ranges = normalize(line);
+        match ranges.diagnostics.iter().find(|d| d.is_error()) {
+            Some(malformed) => Err(malformed.to_parse_error(line)),
+            None => Ok(ranges),
+        }
+    }
+
+    /// Part 1
+    fn part_one(ranges: &Ranges) -> Result<Num, Overflow> {
+        twice_by(&ranges.merged, Radix::DECIMAL, Measure::Sum)
+    }
+
+    /// Part 2
+    fn part_two(ranges: &Ranges) -> Result<Num, Overflow> {
+        at_least_twice_by(&ranges.merged, Radix::DECIMAL, Measure::Sum)
+    }
+
+    fn part_one_exact(ranges: &Ranges) -> Option<BigUint> {
+        twice_by(&ranges.merged, Radix::DECIMAL, Measure::Sum).ok()
+    }
+
+    fn part_two_exact(ranges: &Ranges) -> Option<BigUint> {
+        at_least_twice_by(&ranges.merged, Radix::DECIMAL, Measure::Sum).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    /// The ranges as an input line would list them.
+    fn parsed(ranges: &[(Num, Num)]) -> Ranges {
+        let line: Vec<String> = ranges.iter().map(|(a, b)| format!("{}-{}", a, b)).collect();
+        normalize(&line.join(","))
+    }
+
+    #[test]
+    fn part_one_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_one(&input), Ok(1227775554));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day02::parse(EXAMPLE).unwrap();
+        assert_eq!(Day02::part_two(&input), Ok(4174379265));
+    }
+
+    #[test]
+    fn diagnoses_the_ranges_line() {
+        let line = "11-22, 30-25,20-40,,x,5-,95-115, 1-2 - 3";
+        let ranges = normalize(line);
+        assert_eq!(ranges.listed, [(11, 22), (25, 30), (20, 40), (95, 115)]);
+        assert_eq!(ranges.merged, [(11, 40), (95, 115)]);
+        let found: Vec<(usize, usize, String)> = ranges
+            .diagnostics
+            .iter()
+            .map(|d| (d.offset, d.len, d.issue.to_string()))
+            .collect();
+        let expected = [
+            (7, 5, "range bounds are reversed; read as 25-30"),
+            (13, 5, "range overlaps 11-22; the two are merged"),
+            (13, 5, "range overlaps 25-30; the two are merged"),
+            (20, 1, "malformed range 'x', expected A-B"),
+            (22, 2, "invalid range bound ''"),
+            (35, 5, "invalid range bound '2 - 3'"),
+        ];
+        let expected: Vec<_> = expected
+            .iter()
+            .map(|&(offset, len, message)| (offset, len, message.to_string()))
+            .collect();
+        assert_eq!(found, expected);
+
+        let error = Day02::parse(line).unwrap_err();
+        assert_eq!((error.column, error.len), (21, 1));
+        assert_eq!(error.message, "malformed range 'x', expected A-B");
+    }
+
+    #[test]
+    fn merges_into_disjoint_ranges() {
+        testing::check(
+            "day 2 merge",
+            2000,
+            |rng| {
+                (0..rng.usize(0, 6))
+                    .map(|_| {
+                        let a = rng.range(0, 60) as Num;
+                        (a, a + rng.range(0, 15) as Num)
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |ranges| shrink_ranges(ranges),
+            |ranges| {
+                let Ranges {
+                    listed,
+                    merged,
+                    diagnostics,
+                } = parsed(ranges);
+                testing::expect_eq(&listed, ranges)?;
+                if merged.windows(2).any(|w| w[1].0 <= w[0].1 + 1) {
+                    return Err(format!("{:?} are not disjoint and apart", merged));
+                }
+                let ids = |ranges: &[(Num, Num)]| {
+                    let mut ids: Vec<Num> = ranges.iter().flat_map(|&(a, b)| a..=b).collect();
+                    ids.sort_unstable();
+                    ids.dedup();
+                    ids
+                };
+                testing::expect_eq(ids(&merged), ids(ranges))?;
+                let overlapping = (0..ranges.len()).any(|i| {
+                    (0..i).any(|j| ranges[i].0 <= ranges[j].1 && ranges[j].0 <= ranges[i].1)
+                });
+                let flagged = diagnostics
+                    .iter()
+                    .any(|d| matches!(d.issue, Issue::Overlap { .. }));
+                testing::expect_eq(flagged, overlapping)
+            },
+        );
+    }
+
+    /// Whether the digits of `id` are some block repeated `repeats` times (any number of times,
+    /// at least twice, if `repeats` is `None`).
+    fn is_repetition(id: Num, radix: Radix, repeats: Option<usize>) -> bool {
+        let digits = digits(id, radix);
+        let len = digits.len();
+        (1..len)
+            .filter(|&k| len.is_multiple_of(k))
+            .any(|k| repeats.is_none_or(|t| t * k == len) && digits == digits[..k].repeat(len / k))
+    }
+
+    /// Sums every distinct ID in the ranges that is a repetition, checking them one by one.
+    fn id_by_id(ranges: &[(Num, Num)], radix: Radix, repeats: Option<usize>) -> Num {
+        let mut ids: Vec<Num> = ranges.iter().flat_map(|&(a, b)| a..=b).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids.into_iter()
+            .filter(|&id| is_repetition(id, radix, repeats))
+            .sum()
+    }
+
+    /// Up to four ranges, each starting near a random repetition so that most contain some.
+    fn ranges_near_repetitions(rng: &mut testing::Rng, radix: Radix) -> Vec<(Num, Num)> {
+        let r = radix.get() as u64;
+        (0..rng.usize(1, 4))
+            .map(|_| {
+                let mut block = vec![rng.range(1, r - 1)];
+                block.extend((0..rng.usize(0, 2)).map(|_| rng.range(0, r - 1)));
+                let repeated = block.repeat(rng.usize(1, 10 / block.len()));
+                let centre = repeated.iter().fold(0, |n, &d| n * r + d);
+                let a = centre.saturating_sub(rng.range(0, 200));
+                let b = a + rng.range(0, 400);
+                (a as Num, b as Num)
+            })
+            .collect()
+    }
+
+    fn shrink_ranges(ranges: &[(Num, Num)]) -> Vec<Vec<(Num, Num)>> {
+        let mut smaller = testing::shrink_vec(ranges);
+        smaller.extend(testing::shrink_each(ranges, |&(a, b)| {
+            let width = (b - a) as u64;
+            let mut narrower: Vec<_> = testing::shrink_u64(width)
+                .into_iter()
+                .map(|w| (a, a + w as Num))
+                .collect();
+            narrower.extend(
+                testing::shrink_u64(width)
+                    .into_iter()
+                    .map(|w| (b - w as Num, b)),
+            );
+            narrower
+        }));
+        smaller
+    }
+
+    #[test]
+    fn matches_digit_string_reference() {
+        let decimal = Radix::DECIMAL;
+        testing::check(
+            "day 2",
+            2000,
+            |rng| ranges_near_repetitions(rng, decimal),
+            |ranges| shrink_ranges(ranges),
+            |ranges| {
+                testing::expect_eq(
+                    Day02::part_one(&parsed(ranges)),
+                    Ok(id_by_id(ranges, decimal, Some(2))),
+                )?;
+                testing::expect_eq(
+                    Day02::part_two(&parsed(ranges)),
+                    Ok(id_by_id(ranges, decimal, None)),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn matches_digit_string_reference_in_any_radix() {
+        testing::check(
+            "day 2 radix",
+            500,
+            |rng| {
+                let radix = Radix::new(rng.range(2, 36) as u32);
+                (radix, ranges_near_repetitions(rng, radix))
+            },
+            |(radix, ranges)| {
+                shrink_ranges(ranges)
+                    .into_iter()
+                    .map(|smaller| (*radix, smaller))
+                    .collect()
+            },
+            |(radix, ranges)| {
+                testing::expect_eq(
+                    repeated_twice::<Num>(ranges, *radix),
+                    Ok(id_by_id(ranges, *radix, Some(2))),
+                )?;
+                testing::expect_eq(
+                    repeated_at_least_twice::<Num>(ranges, *radix),
+                    Ok(id_by_id(ranges, *radix, None)),
+                )
+            },
+        );
+    }
+
+    #[test]
+    fn lists_each_ranges_ids_in_order() {
+        testing::check(
+            "day 2 ids",
+            300,
+            |rng| {
+                let radix = Radix::new(*rng.pick(&[2, 3, 10, 16, 36]));
+                (radix, ranges_near_repetitions(rng, radix))
+            },
+            |(radix, ranges)| {
+                shrink_ranges(ranges)
+                    .into_iter()
+                    .map(|smaller| (*radix, smaller))
+                    .collect()
+            },
+            |(radix, ranges)| {
+                let tallies = tally_ranges::<Num>(ranges, *radix).map_err(|e| e.to_string())?;
+                for (&(a, b), tally) in ranges.iter().zip(tallies) {
+                    let expected: Vec<Repetition> = (a..=b)
+                        .filter(|&id| is_repetition(id, *radix, None))
+                        .map(|id| {
+                            let digits = digits(id, *radix);
+                            let k = (1..digits.len())
+                                .find(|&k| {
+                                    digits.len().is_multiple_of(k)
+                                        && digits == digits[..k].repeat(digits.len() / k)
+                                })
+                                .unwrap();
+                            let block = digits[..k]
+                                .iter()
+                                .fold(0, |n, &d| n * radix.get() as Num + d as Num);
+                            Repetition {
+                                id,
+                                block,
+                                repeats: (digits.len() / k) as u32,
+                            }
+                        })
+                        .collect();
+                    let ids: Vec<Repetition> = invalid_ids((a, b), *radix).collect();
+                    testing::expect_eq(&ids, &expected)?;
+                    let twice: Vec<Num> =
+                        ids.iter().filter(|r| r.is_twice()).map(|r| r.id).collect();
+                    testing::expect_eq(
+                        &tally.twice,
+                        &Tally {
+                            count: twice.len() as Num,
+                            sum: twice.iter().sum(),
+                        },
+                    )?;
+                    testing::expect_eq(
+                        &tally.at_least_twice,
+                        &Tally {
+                            count: ids.len() as Num,
+                            sum: ids.iter().map(|r| r.id).sum(),
+                        },
+                    )?;
+                }
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn lists_ids_lazily_across_the_full_domain() {
+        let mut ids = invalid_ids((0, Num::MAX), Radix::DECIMAL);
+        let first: Vec<Num> = ids.by_ref().take(10).map(|r| r.id).collect();
+        assert_eq!(first, [11, 22, 33, 44, 55, 66, 77, 88, 99, 111]);
+
+        let factor = compute_factor(Radix::DECIMAL, 13, 3).unwrap();
+        let largest = Num::MAX / factor * factor;
+        let mut top = invalid_ids((largest - factor, Num::MAX), Radix::DECIMAL);
+        assert_eq!(top.next().map(|r| r.id), Some(largest - factor));
+        assert_eq!(
+            top.next(),
+            Some(Repetition {
+                id: largest,
+                block: Num::MAX / factor,
+                repeats: 3
+            })
+        );
+        assert_eq!(top.next(), None);
+        assert_eq!(top.next(), None);
+    }
+
+    /// An independent test of whether a single ID matches a pattern.
+    type Reference = fn(Num, Radix) -> bool;
+
+    /// Each pattern with its reference.
+    fn patterns() -> Vec<(Box<dyn Pattern>, Reference)> {
+        vec![
+            (Box::new(Twice), |id, radix| {
+                is_repetition(id, radix, Some(2))
+            }),
+            (Box::new(AtLeastTwice), |id, radix| {
+                is_repetition(id, radix, None)
+            }),
+            (Box::new(RepeatedExactly::new(3)), |id, radix| {
+                is_repetition(id, radix, Some(3))
+            }),
+            (Box::new(Palindrome), |id, radix| {
+                let digits = digits(id, radix);
+                let mut reversed = digits.clone();
+                reversed.reverse();
+                id > 0 && digits == reversed
+            }),
+            (Box::new(NonDecreasing), |id, radix| {
+                let digits = digits(id, radix);
+                id > 0 && digits.windows(2).all(|w| w[0] <= w[1])
+            }),
+        ]
+    }
+
+    #[test]
+    fn patterns_list_their_matches_in_order() {
+        let patterns = patterns();
+        testing::check(
+            "day 2 patterns",
+            300,
+            |rng| {
+                let radix = Radix::new(*rng.pick(&[2, 3, 10, 16, 36]));
+                (radix, ranges_near_repetitions(rng, radix))
+            },
+            |(radix, ranges)| {
+                shrink_ranges(ranges)
+                    .into_iter()
+                    .map(|smaller| (*radix, smaller))
+                    .collect()
+            },
+            |(radix, ranges)| {
+                for (pattern, reference) in &patterns {
+                    for &(a, b) in ranges {
+                        let expected: Vec<Num> =
+                            (a..=b).filter(|&id| reference(id, *radix)).collect();
+                        let matched: Vec<Num> =
+                            (a..=b).filter(|&id| pattern.matches(id, *radix)).collect();
+                        testing::expect_eq(&matched, &expected)?;
+                        testing::expect_eq(&pattern.ids((a, b), *radix).collect(), &expected)?;
+                    }
+                    let mut all: Vec<Num> = ranges.iter().flat_map(|&(a, b)| a..=b).collect();
+                    all.sort_unstable();
+                    all.dedup();
+                    all.retain(|&id| reference(id, *radix));
+                    testing::expect_eq(&flag(ranges, *radix, pattern.as_ref()).collect(), &all)?;
+                }
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn patterns_stop_at_the_end_of_the_domain() {
+        let top = (Num::MAX - 20_000, Num::MAX);
+        for (pattern, reference) in patterns() {
+            for radix in [Radix::new(2), Radix::DECIMAL, Radix::new(36)] {
+                let expected: Vec<Num> =
+                    (top.0..=top.1).filter(|&id| reference(id, radix)).collect();
+                assert_eq!(pattern.ids(top, radix).collect::<Vec<_>>(), expected);
+            }
+            let first: Vec<Num> = pattern.ids((0, Num::MAX), Radix::DECIMAL).take(3).collect();
+            let expected: Vec<Num> = (0..)
+                .filter(|&id| reference(id, Radix::DECIMAL))
+                .take(3)
+                .collect();
+            assert_eq!(first, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "a block must repeat at least twice")]
+    fn rejects_a_single_repeat() {
+        RepeatedExactly::new(1);
+    }
+
+    #[test]
+    fn reads_ids_in_binary_and_hex() {
+        let (binary, hex) = (Radix::new(2), Radix::new(16));
+        // 0b1010 = 10 and 0b111 = 7 repeat a block; 0b1001 = 9 does not.
+        assert_eq!(repeated_twice::<Num>(&[(7, 10)], binary), Ok(10));
+        assert_eq!(
+            repeated_at_least_twice::<Num>(&[(7, 10)], binary),
+            Ok(7 + 10)
+        );
+        assert_eq!(repeated_twice::<Num>(&[(0xabab, 0xabab)], hex), Ok(0xabab));
+        // Every digit length up to 128 bits, where the top bucket is cut short by u128::MAX.
+        let whole = [(0, Num::MAX)];
+        assert_eq!(
+            repeated_at_least_twice::<Num>(&whole, binary),
+            Err(Overflow { width: "u128" })
+        );
+        assert!(repeated_at_least_twice::<BigUint>(&whole, Radix::new(36)).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be in 2..=36")]
+    fn rejects_radix_out_of_range() {
+        Radix::new(37);
+    }
+
+    #[test]
+    fn sums_whole_digit_lengths() {
+        let ranges = vec![(0, 99_999)];
+        assert_eq!(
+            Day02::part_one(&parsed(&ranges)),
+            Ok(id_by_id(&ranges, Radix::DECIMAL, Some(2)))
+        );
+        assert_eq!(
+            Day02::part_two(&parsed(&ranges)),
+            Ok(id_by_id(&ranges, Radix::DECIMAL, None))
+        );
+    }
+
+    #[test]
+    fn sums_the_full_domain_exactly() {
+        let whole = vec![(0, Num::MAX)];
+        let width = Overflow { width: "u128" };
+        assert_eq!(Day02::part_one(&parsed(&whole)), Err(width));
+        assert_eq!(Day02::part_two(&parsed(&whole)), Err(width));
+        let (one, two) = (
+            Day02::part_one_exact(&parsed(&whole)).unwrap(),
+            Day02::part_two_exact(&parsed(&whole)).unwrap(),
+        );
+        assert!(two > one);
+
+        // Cutting the domain into overlapping pieces must not change the sums.
+        let mut rng = testing::Rng::new(2);
+        for _ in 0..20 {
+            let mut cuts: Vec<Num> = (0..rng.usize(1, 8))
+                .map(|_| {
+                    let bits = (rng.next_u64() as Num) << 64 | rng.next_u64() as Num;
+                    bits >> rng.range(0, 127)
+                })
+                .collect();
+            cuts.extend([0, Num::MAX]);
+            cuts.sort_unstable();
+            let pieces: Vec<_> = cuts
+                .windows(2)
+                .map(|w| (w[0].saturating_sub(rng.range(0, 3) as Num), w[1]))
+                .collect();
+            assert_eq!(Day02::part_one_exact(&parsed(&pieces)).as_ref(), Some(&one));
+            assert_eq!(Day02::part_two_exact(&parsed(&pieces)).as_ref(), Some(&two));
+        }
+
+        // Near the top of the domain the fixed-width sums still fit: a 13-digit block repeated
+        // three times is the largest invalid ID.
+        let factor = compute_factor(Radix::DECIMAL, 13, 3).unwrap();
+        let largest = Num::MAX / factor * factor;
+        let top = vec![(largest - 1, Num::MAX)];
+        assert_eq!(Day02::part_one(&parsed(&top)), Ok(0));
+        assert_eq!(Day02::part_two(&parsed(&top)), Ok(largest));
+        assert_eq!(
+            Day02::part_two_exact(&parsed(&top)),
+            Some(BigUint::from(largest))
+        );
+    }
+}
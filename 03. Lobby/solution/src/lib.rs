@@ -0,0 +1,301 @@
+use std::io::BufRead;
+
+use aoc_common::{
+    AnswerError, BigUint, InputError, Natural, Overflow, ParseError, Solution, Streaming, input,
+};
+
+/// Batteries turned on in each bank for part one, the fewest of either part.
+const PART_ONE_BATTERIES: usize = 2;
+/// Batteries turned on in each bank for part two.
+const PART_TWO_BATTERIES: usize = 12;
+
+/// The bank's digits. A bank too short for part one is rejected; one too short only for part two
+/// still parses, and part two reports it.
+fn digits_from_str(line_no: usize, line: &str) -> Result<Vec<u8>, ParseError> {
+    let s = line.trim();
+    let digits = s
+        .char_indices()
+        .map(|(i, c)| match c.to_digit(10) {
+            Some(d) => Ok(d as u8),
+            None => {
+                let bad = &s[i..i + c.len_utf8()];
+                let msg = format!("invalid character '{}', expected a digit", c);
+                Err(ParseError::at(Day03::DAY, line_no, line, bad, msg))
+            }
+        })
+        .collect::<Result<Vec<u8>, _>>()?;
+    if digits.len() < PART_ONE_BATTERIES {
+        let msg = format!(
+            "bank has {} digits, need at least {}",
+            digits.len(),
+            PART_ONE_BATTERIES
+        );
+        return Err(ParseError::at(Day03::DAY, line_no, line, s, msg));
+    }
+    Ok(digits)
+}
+
+// Generic: lấy subsequence lớn nhất độ dài k
+fn max_subseq_k(digits: &[u8], k: usize) -> Vec<usize> {
+    let n = digits.len();
+    // Callers check the bank is long enough first.
+    debug_assert!(n >= k, "line has fewer than {} digits", k);
+
+    // Indices of the kept digits.
+    let mut stack: Vec<usize> = Vec::with_capacity(k);
+    let mut to_remove = n - k;
+
+    for (i, &d) in digits.iter().enumerate() {
+        while !stack.is_empty() && to_remove > 0 && digits[*stack.last().unwrap()] < d {
+            stack.pop();
+            to_remove -= 1;
+        }
+        stack.push(i);
+    }
+
+    stack.truncate(k);
+    stack
+}
+
+/// The largest `k`-digit number that keeps the bank's digits in order.
+fn joltage<N: Natural>(digits: &[u8], k: usize) -> Result<N, Overflow> {
+    let ten = N::from_u64(10);
+    let mut num = N::zero();
+    let chosen = max_subseq_k(digits, k);
+    for &i in &chosen {
+        num = num
+            .checked_mul(&ten)?
+            .checked_add(&N::from_u64(digits[i] as u64))?;
+    }
+    aoc_common::trace!(
+        3,
+        "bank",
+        k = k,
+        indices = chosen,
+        joltage = num.to_string()
+    );
+    Ok(num)
+}
+
+/// [`joltage`] of the `bank`th bank (counting from 1), which may be too short to turn on `k`.
+fn bank_joltage<N: Natural>(bank: usize, digits: &[u8], k: usize) -> Result<N, AnswerError> {
+    if digits.len() < k {
+        return Err(AnswerError::Unanswerable(format!(
+            "bank {} has {} digits, need at least {}",
+            bank,
+            digits.len(),
+            k
+        )));
+    }
+    Ok(joltage(digits, k)?)
+}
+
+/// Sum over all banks of the largest `k`-digit number that keeps the digits in order.
+fn total_joltage<N: Natural>(banks: &[Vec<u8>], k: usize) -> Result<N, AnswerError> {
+    banks
+        .iter()
+        .enumerate()
+        .try_fold(N::zero(), |total, (i, digits)| {
+            Ok(total.checked_add(&bank_joltage(i + 1, digits, k)?)?)
+        })
+}
+
+/// Running totals of both parts, one bank at a time. A total stays at its first error.
+pub struct Totals {
+    pub banks: usize,
+    pub one: Result<u128, AnswerError>,
+    pub two: Result<u128, AnswerError>,
+}
+
+impl Default for Totals {
+    fn default() -> Self {
+        Totals {
+            banks: 0,
+            one: Ok(0),
+            two: Ok(0),
+        }
+    }
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Vec<u8>>;
+    type Answer = Result<u128, AnswerError>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+        input::parse_str(input, Self::parse_reader)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Vec<Vec<u8>>, InputError> {
+        let mut banks = Vec::new();
+        input::for_each_line(reader, |line_no, line| {
+            if !line.trim().is_empty() {
+                banks.push(digits_from_str(line_no, line)?);
+            }
+            Ok(())
+        })?;
+        Ok(banks)
+    }
+
+    /* ---------------------- PART 1 ------------------------- */
+
+    fn part_one(banks: &Vec<Vec<u8>>) -> Result<u128, AnswerError> {
+        total_joltage(banks, PART_ONE_BATTERIES)
+    }
+
+    /* ---------------------- PART 2 ------------------------- */
+
+    fn part_two(banks: &Vec<Vec<u8>>) -> Result<u128, AnswerError> {
+        total_joltage(banks, PART_TWO_BATTERIES)
+    }
+
+    fn part_one_exact(banks: &Vec<Vec<u8>>) -> Option<BigUint> {
+        total_joltage(banks, PART_ONE_BATTERIES).ok()
+    }
+
+    fn part_two_exact(banks: &Vec<Vec<u8>>) -> Option<BigUint> {
+        total_joltage(banks, PART_TWO_BATTERIES).ok()
+    }
+}
+
+impl Streaming for Day03 {
+    type State = Totals;
+
+    fn feed(totals: &mut Totals, line_no: usize, line: &str) -> Result<(), ParseError> {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+        let digits = digits_from_str(line_no, line)?;
+        totals.banks += 1;
+        let bank = totals.banks;
+        for (total, k) in [
+            (&mut totals.one, PART_ONE_BATTERIES),
+            (&mut totals.two, PART_TWO_BATTERIES),
+        ] {
+            if let Ok(t) = total {
+                *total =
+                    bank_joltage(bank, &digits, k).and_then(|j| Ok(Natural::checked_add(t, &j)?));
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(totals: Totals) -> [Result<u128, AnswerError>; 2] {
+        [totals.one, totals.two]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part_one(&input), Ok(357));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day03::parse(EXAMPLE).unwrap();
+        assert_eq!(Day03::part_two(&input), Ok(3121910778619));
+    }
+
+    #[test]
+    fn streams_example() {
+        let answers = input::stream::<Day03>(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(answers, [Ok(357), Ok(3121910778619)]);
+    }
+
+    #[test]
+    fn rejects_banks_too_short_for_part_one() {
+        let err = Day03::parse("987654321111111\n 8 \n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.message, "bank has 1 digits, need at least 2");
+    }
+
+    #[test]
+    fn short_banks_fail_only_part_two() {
+        let input = "987654321111111\n 81111111119 \n";
+        let short = Err(AnswerError::Unanswerable(
+            "bank 2 has 11 digits, need at least 12".to_string(),
+        ));
+        let banks = Day03::parse(input).unwrap();
+        assert_eq!(Day03::part_one(&banks), Ok(98 + 89));
+        assert_eq!(Day03::part_two(&banks), short);
+
+        let streamed = input::stream::<Day03>(&mut input.as_bytes()).unwrap();
+        assert_eq!(streamed, [Ok(98 + 89), short]);
+    }
+
+    /// The largest `k`-digit number formed by keeping `k` of the digits in order, found by
+    /// trying every choice of positions.
+    fn best_subsequence(digits: &[u8], k: usize) -> u128 {
+        fn go(digits: &[u8], k: usize, acc: u128) -> u128 {
+            if k == 0 {
+                return acc;
+            }
+            (0..=digits.len() - k)
+                .map(|i| go(&digits[i + 1..], k - 1, acc * 10 + digits[i] as u128))
+                .max()
+                .unwrap()
+        }
+        go(digits, k, 0)
+    }
+
+    #[test]
+    fn matches_exhaustive_reference() {
+        testing::check(
+            "day 3",
+            2000,
+            |rng| {
+                // Few distinct digits make ties, where a greedy choice is easiest to get wrong.
+                let max_digit = *rng.pick(&[2, 3, 9]);
+                (0..rng.usize(1, 3))
+                    .map(|_| {
+                        (0..rng.usize(12, 16))
+                            .map(|_| rng.range(1, max_digit) as u8)
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>()
+            },
+            |banks| {
+                let mut smaller = testing::shrink_vec(banks);
+                smaller.retain(|b| !b.is_empty());
+                smaller.extend(testing::shrink_each(banks, |bank| {
+                    testing::shrink_vec(bank)
+                        .into_iter()
+                        .filter(|b| b.len() >= 12)
+                        .chain(testing::shrink_each(bank, |&d| (1..d).collect::<Vec<_>>()))
+                        .collect()
+                }));
+                smaller
+            },
+            |banks| {
+                for k in [2, 12] {
+                    let expected = banks.iter().map(|b| best_subsequence(b, k)).sum();
+                    testing::expect_eq(total_joltage::<u128>(banks, k), Ok(expected))?;
+                }
+                Ok(())
+            },
+        );
+    }
+
+    #[test]
+    fn long_selections_need_exact_arithmetic() {
+        // 39 nines do not fit in a u128.
+        let banks = vec![vec![9; 40]];
+        assert_eq!(
+            total_joltage::<u128>(&banks, 39),
+            Err(AnswerError::Overflow(Overflow { width: "u128" }))
+        );
+        let exact: BigUint = total_joltage(&banks, 39).unwrap();
+        assert_eq!(exact.to_string(), "9".repeat(39));
+    }
+}
@@ -0,0 +1,179 @@
+use std::collections::VecDeque;
+
+use aoc_common::{Connectivity, Grid, ParseError, Pos, Solution};
+
+/// The number of paper rolls among the eight cells around `pos`.
+fn rolls_around(grid: &Grid<char>, pos: Pos) -> usize {
+    grid.neighbors(pos, Connectivity::Eight)
+        .filter(|&n| grid[n] == '@')
+        .count()
+}
+
+fn count_accessible(grid: &Grid<char>) -> u64 {
+    grid.positions()
+        .filter(|&pos| grid[pos] == '@' && rolls_around(grid, pos) < 4)
+        .count() as u64
+}
+
+fn count_removable(grid: &Grid<char>) -> u64 {
+    // degree and alive mask
+    let mut alive = grid.map(|&c| c == '@');
+    let mut deg = Grid::new(grid.width(), grid.height(), 0);
+    for pos in grid.positions() {
+        if alive[pos] {
+            deg[pos] = rolls_around(grid, pos);
+        }
+    }
+
+    // Each roll carries the wave it became removable in: the first wave is the rolls that are
+    // accessible at the start, the next those freed by removing them, and so on.
+    let mut queue: VecDeque<(Pos, usize)> = grid
+        .positions()
+        .filter(|&pos| alive[pos] && deg[pos] < 4)
+        .map(|pos| (pos, 1))
+        .collect();
+
+    let mut removed = 0u64;
+    let tracing = aoc_common::trace::enabled();
+    let mut wave = (1, Vec::new());
+
+    while let Some((pos, w)) = queue.pop_front() {
+        if !alive[pos] {
+            continue;
+        }
+        if tracing && w != wave.0 {
+            trace_wave(&wave);
+            wave = (w, Vec::new());
+        }
+
+        alive[pos] = false;
+        removed += 1;
+        if tracing {
+            wave.1.push(pos);
+        }
+
+        for n in grid.neighbors(pos, Connectivity::Eight) {
+            if alive[n] {
+                deg[n] = deg[n].saturating_sub(1);
+                if deg[n] < 4 {
+                    queue.push_back((n, w + 1));
+                }
+            }
+        }
+    }
+    if tracing && !wave.1.is_empty() {
+        trace_wave(&wave);
+    }
+
+    removed
+}
+
+fn trace_wave((wave, cells): &(usize, Vec<Pos>)) {
+    aoc_common::trace!(4, "wave", wave = wave, removed = cells.len(), cells = cells);
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Grid<char>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse(Self::DAY, input, "@.")
+    }
+
+    fn part_one(grid: &Grid<char>) -> u64 {
+        count_accessible(grid)
+    }
+
+    fn part_two(grid: &Grid<char>) -> u64 {
+        count_removable(grid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_one(&input), 13);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day04::parse(EXAMPLE).unwrap();
+        assert_eq!(Day04::part_two(&input), 43);
+    }
+
+    /// Rolls with fewer than four rolls among the eight cells around them, counted by hand.
+    fn accessible(rolls: &Grid<char>) -> Vec<Pos> {
+        rolls
+            .positions()
+            .filter(|&pos| {
+                let around = (-1..=1)
+                    .flat_map(|dr| (-1..=1).map(move |dc| (dr, dc)))
+                    .filter(|&d| d != (0, 0))
+                    .filter(|&d| rolls.offset(pos, d).is_some_and(|n| rolls[n] == '@'))
+                    .count();
+                rolls[pos] == '@' && around < 4
+            })
+            .collect()
+    }
+
+    /// Removes every accessible roll at once, rescans the whole grid and repeats until none is
+    /// left to remove.
+    fn peel_reference(grid: &Grid<char>) -> u64 {
+        let mut rolls = grid.clone();
+        let mut removed = 0;
+        loop {
+            let wave = accessible(&rolls);
+            if wave.is_empty() {
+                return removed;
+            }
+            removed += wave.len() as u64;
+            for pos in wave {
+                rolls[pos] = '.';
+            }
+        }
+    }
+
+    #[test]
+    fn matches_repeated_peel_reference() {
+        testing::check(
+            "day 4",
+            1000,
+            |rng| {
+                let (w, h) = (rng.usize(1, 12), rng.usize(1, 12));
+                let density = rng.range(1, 9);
+                let mut grid = Grid::new(w, h, '.');
+                for pos in grid.positions() {
+                    if rng.range(0, 9) < density {
+                        grid[pos] = '@';
+                    }
+                }
+                grid
+            },
+            |grid| {
+                grid.positions()
+                    .filter(|&pos| grid[pos] == '@')
+                    .map(|pos| {
+                        let mut fewer = grid.clone();
+                        fewer[pos] = '.';
+                        fewer
+                    })
+                    .collect()
+            },
+            |grid| {
+                testing::expect_eq(count_accessible(grid), accessible(grid).len() as u64)?;
+                testing::expect_eq(count_removable(grid), peel_reference(grid))
+            },
+        );
+    }
+}
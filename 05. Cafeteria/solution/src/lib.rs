@@ -0,0 +1,248 @@
+use std::io::BufRead;
+use std::mem;
+
+use aoc_common::{InputError, ParseError, Solution, Streaming, input};
+
+/// Parses the ID `s`, a trimmed subslice of `line`.
+fn parse_u128(line_no: usize, line: &str, s: &str) -> Result<u128, ParseError> {
+    if s.is_empty() {
+        return Err(ParseError::at(
+            Day05::DAY,
+            line_no,
+            line,
+            s,
+            "missing number",
+        ));
+    }
+    s.parse::<u128>().map_err(|_| {
+        let msg = format!("invalid number '{}'", s);
+        ParseError::at(Day05::DAY, line_no, line, s, msg)
+    })
+}
+
+/// Parses a trimmed, non-empty `A-B` range line; reversed bounds are swapped.
+fn parse_range(line_no: usize, line: &str) -> Result<(u128, u128), ParseError> {
+    let s = line.trim();
+    let Some((a, b)) = s.split_once('-') else {
+        let msg = format!("malformed range '{}', expected A-B", s);
+        return Err(ParseError::at(Day05::DAY, line_no, line, s, msg));
+    };
+    let a = parse_u128(line_no, line, a.trim())?;
+    let b = parse_u128(line_no, line, b.trim())?;
+    Ok(if a <= b { (a, b) } else { (b, a) })
+}
+
+fn merge_ranges(mut ranges: Vec<(u128, u128)>) -> Vec<(u128, u128)> {
+    if ranges.is_empty() {
+        return ranges;
+    }
+    ranges.sort_by_key(|&(a, _)| a);
+    let count = ranges.len();
+    let mut merged = Vec::with_capacity(count);
+    let mut cur = ranges[0];
+    for (a, b) in ranges.into_iter().skip(1) {
+        if a <= cur.1 + 1 {
+            // overlapping or touching
+            if b > cur.1 {
+                cur.1 = b;
+            }
+        } else {
+            merged.push(cur);
+            cur = (a, b);
+        }
+    }
+    merged.push(cur);
+    aoc_common::trace!(5, "merged", ranges = count, merged = merged);
+    merged
+}
+
+/// Whether `id` lies in one of the sorted, disjoint `merged` ranges.
+fn is_fresh(merged: &[(u128, u128)], id: u128) -> bool {
+    // check membership by binary search
+    let mut lo = 0usize;
+    let mut hi = merged.len();
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        let (a, b) = merged[mid];
+        if id < a {
+            hi = mid;
+        } else if id > b {
+            lo = mid + 1;
+        } else {
+            return true;
+        }
+    }
+    false
+}
+
+/// The number of IDs covered by the sorted, disjoint `merged` ranges.
+fn covered(merged: &[(u128, u128)]) -> u128 {
+    merged.iter().map(|&(a, b)| b - a + 1).sum()
+}
+
+/// The fresh ingredient ID ranges followed, after a blank line, by the available IDs.
+pub struct Database {
+    pub ranges: Vec<(u128, u128)>,
+    pub ids: Vec<u128>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Database;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Database, ParseError> {
+        input::parse_str(input, Self::parse_reader)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Database, InputError> {
+        let mut ranges = Vec::new();
+        let mut ids = Vec::new();
+        // Ranges run until the first blank line; the remaining lines are query IDs.
+        let mut in_ids = false;
+        input::for_each_line(reader, |line_no, line| {
+            let s = line.trim();
+            match (in_ids, s.is_empty()) {
+                (false, true) => in_ids = true,
+                (false, false) => ranges.push(parse_range(line_no, line)?),
+                (true, true) => {}
+                (true, false) => ids.push(parse_u128(line_no, line, s)?),
+            }
+            Ok(())
+        })?;
+        Ok(Database { ranges, ids })
+    }
+
+    fn part_one(db: &Database) -> u128 {
+        let merged = merge_ranges(db.ranges.clone());
+        db.ids.iter().filter(|&&id| is_fresh(&merged, id)).count() as u128
+    }
+
+    fn part_two(db: &Database) -> u128 {
+        covered(&merge_ranges(db.ranges.clone()))
+    }
+}
+
+/// Streaming state: the ranges are kept (and merged once the blank line is reached), while
+/// the query IDs, usually the bulk of the input, are only counted.
+#[derive(Default)]
+pub struct Inventory {
+    ranges: Vec<(u128, u128)>,
+    in_ids: bool,
+    fresh: u128,
+}
+
+impl Streaming for Day05 {
+    type State = Inventory;
+
+    fn feed(inv: &mut Inventory, line_no: usize, line: &str) -> Result<(), ParseError> {
+        let s = line.trim();
+        match (inv.in_ids, s.is_empty()) {
+            (false, true) => {
+                inv.ranges = merge_ranges(mem::take(&mut inv.ranges));
+                inv.in_ids = true;
+            }
+            (false, false) => inv.ranges.push(parse_range(line_no, line)?),
+            (true, true) => {}
+            (true, false) => {
+                if is_fresh(&inv.ranges, parse_u128(line_no, line, s)?) {
+                    inv.fresh += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn finish(inv: Inventory) -> [u128; 2] {
+        [inv.fresh, covered(&merge_ranges(inv.ranges))]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part_one(&input), 3);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day05::parse(EXAMPLE).unwrap();
+        assert_eq!(Day05::part_two(&input), 14);
+    }
+
+    #[test]
+    fn streams_example() {
+        let answers = input::stream::<Day05>(&mut EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(answers, [3, 14]);
+    }
+
+    /// Fresh IDs among the queries and IDs covered by the ranges, testing each ID against every
+    /// range.
+    fn id_by_id(ranges: &[(u128, u128)], ids: &[u128]) -> [u128; 2] {
+        let fresh = |id: u128| ranges.iter().any(|&(a, b)| a <= id && id <= b);
+        let fresh_queries = ids.iter().filter(|&&id| fresh(id)).count() as u128;
+        let top = ranges.iter().map(|&(_, b)| b).max().unwrap_or(0);
+        let covered = (0..=top).filter(|&id| fresh(id)).count() as u128;
+        [fresh_queries, covered]
+    }
+
+    #[test]
+    fn matches_id_by_id_reference() {
+        testing::check(
+            "day 5",
+            1000,
+            |rng| {
+                let ranges: Vec<(u128, u128)> = (0..rng.usize(0, 6))
+                    .map(|_| {
+                        let a = rng.range(0, 60) as u128;
+                        (a, a + rng.range(0, 15) as u128)
+                    })
+                    .collect();
+                let ids: Vec<u128> = (0..rng.usize(0, 10))
+                    .map(|_| rng.range(0, 80) as u128)
+                    .collect();
+                (ranges, ids)
+            },
+            |(ranges, ids)| {
+                let mut smaller: Vec<_> = testing::shrink_vec(ranges)
+                    .into_iter()
+                    .map(|r| (r, ids.clone()))
+                    .collect();
+                smaller.extend(
+                    testing::shrink_vec(ids)
+                        .into_iter()
+                        .map(|i| (ranges.clone(), i)),
+                );
+                smaller
+            },
+            |(ranges, ids)| {
+                let expected = id_by_id(ranges, ids);
+                let db = Database {
+                    ranges: ranges.clone(),
+                    ids: ids.clone(),
+                };
+                testing::expect_eq([Day05::part_one(&db), Day05::part_two(&db)], expected)?;
+
+                let mut text: String = ranges
+                    .iter()
+                    .map(|(a, b)| format!("{}-{}\n", a, b))
+                    .collect();
+                text.push('\n');
+                text.extend(ids.iter().map(|id| format!("{}\n", id)));
+                let streamed =
+                    input::stream::<Day05>(&mut text.as_bytes()).map_err(|e| e.to_string())?;
+                testing::expect_eq(streamed, expected)
+            },
+        );
+    }
+}
@@ -0,0 +1,460 @@
+use aoc_common::{BigUint, Natural, Overflow, ParseError, Solution};
+
+/// The number rows and the operator row, padded to a common width.
+pub struct Worksheet {
+    pub grid: Vec<Vec<char>>,
+    pub op_chars: Vec<char>,
+    /// `used[c]` is false for the all-blank columns separating problems.
+    pub used: Vec<bool>,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Worksheet;
+    type Answer = Result<u128, Overflow>;
+
+    fn parse(src: &str) -> Result<Worksheet, ParseError> {
+        // Split into lines (preserve spacing)
+        let mut lines: Vec<&str> = src.lines().collect();
+
+        // Remove trailing blank lines
+        while let Some(last) = lines.last() {
+            if last.trim().is_empty() {
+                lines.pop();
+            } else {
+                break;
+            }
+        }
+
+        if lines.is_empty() {
+            return Ok(Worksheet {
+                grid: Vec::new(),
+                op_chars: Vec::new(),
+                used: Vec::new(),
+            });
+        }
+
+        // Bottom line is operator row
+        let op_line_no = lines.len();
+        let op_line = lines.pop().unwrap();
+
+        // Number rows above it
+        let num_rows = lines;
+
+        // Only digits and spaces above, only operators and spaces below
+        for (i, ln) in num_rows.iter().enumerate() {
+            if let Some((j, c)) = ln
+                .char_indices()
+                .find(|&(_, c)| !c.is_ascii_digit() && c != ' ')
+            {
+                let msg = format!("invalid character '{}' in a number row", c);
+                return Err(ParseError::at_byte(
+                    Self::DAY,
+                    i + 1,
+                    ln,
+                    j,
+                    c.len_utf8(),
+                    msg,
+                ));
+            }
+        }
+        if let Some((j, c)) = op_line
+            .char_indices()
+            .find(|&(_, c)| !matches!(c, '+' | '*' | ' '))
+        {
+            let msg = format!("invalid operator '{}', expected '+' or '*'", c);
+            return Err(ParseError::at_byte(
+                Self::DAY,
+                op_line_no,
+                op_line,
+                j,
+                c.len_utf8(),
+                msg,
+            ));
+        }
+
+        // Determine max width across all rows (all ASCII from here on)
+        let mut width = op_line.len();
+        for ln in &num_rows {
+            width = width.max(ln.len());
+        }
+
+        // Pad number rows
+        let mut grid: Vec<Vec<char>> = Vec::with_capacity(num_rows.len());
+        for ln in &num_rows {
+            let mut v: Vec<char> = ln.chars().collect();
+            if v.len() < width {
+                v.extend(std::iter::repeat_n(' ', width - v.len()));
+            }
+            grid.push(v);
+        }
+
+        // Pad operator row
+        let mut op_chars: Vec<char> = op_line.chars().collect();
+        if op_chars.len() < width {
+            op_chars.extend(std::iter::repeat_n(' ', width - op_chars.len()));
+        }
+
+        // Detect used columns
+        let mut used = vec![false; width];
+        for (c, is_used) in used.iter_mut().enumerate() {
+            *is_used = grid.iter().any(|row| row[c] != ' ');
+        }
+
+        // Every problem needs an operator, and each of its rows holds at most one number
+        let mut col = 0;
+        while col < width {
+            if !used[col] {
+                col += 1;
+                continue;
+            }
+            let start = col;
+            let mut end = col;
+            while end + 1 < width && used[end + 1] {
+                end += 1;
+            }
+
+            if !op_chars[start..=end].iter().any(|&c| c == '+' || c == '*') {
+                let msg = format!(
+                    "missing operator for the problem in columns {}-{}",
+                    start + 1,
+                    end + 1
+                );
+                let from = start.min(op_line.len());
+                let len = (end + 1).min(op_line.len()) - from;
+                return Err(ParseError::at_byte(
+                    Self::DAY,
+                    op_line_no,
+                    op_line,
+                    from,
+                    len,
+                    msg,
+                ));
+            }
+            for (i, ln) in num_rows.iter().enumerate() {
+                let slice = &ln[start.min(ln.len())..(end + 1).min(ln.len())];
+                if slice.trim().contains(' ') {
+                    let msg = format!(
+                        "problem in columns {}-{} has two numbers on one row",
+                        start + 1,
+                        end + 1
+                    );
+                    return Err(ParseError::at(Self::DAY, i + 1, ln, slice, msg));
+                }
+            }
+
+            col = end + 1;
+        }
+
+        Ok(Worksheet {
+            grid,
+            op_chars,
+            used,
+        })
+    }
+
+    fn part_one(sheet: &Worksheet) -> Result<u128, Overflow> {
+        row_totals(sheet)
+    }
+
+    fn part_two(sheet: &Worksheet) -> Result<u128, Overflow> {
+        column_totals(sheet)
+    }
+
+    fn part_one_exact(sheet: &Worksheet) -> Option<BigUint> {
+        row_totals(sheet).ok()
+    }
+
+    fn part_two_exact(sheet: &Worksheet) -> Option<BigUint> {
+        column_totals(sheet).ok()
+    }
+}
+
+/// Grand total with each problem's numbers read across its rows, left to right.
+fn row_totals<N: Natural>(sheet: &Worksheet) -> Result<N, Overflow> {
+    let Worksheet {
+        grid,
+        op_chars,
+        used,
+    } = sheet;
+    let width = used.len();
+
+    // Extract problems left → right
+    let mut col = 0;
+    let mut grand_total = N::zero();
+
+    while col < width {
+        if !used[col] {
+            col += 1;
+            continue;
+        }
+
+        let start = col;
+        let mut end = col;
+        while end + 1 < width && used[end + 1] {
+            end += 1;
+        }
+
+        // Collect numbers (top → bottom)
+        let mut numbers: Vec<N> = Vec::new();
+        for row in grid {
+            let slice: String = row[start..=end].iter().collect();
+            let trimmed = slice.trim();
+            if !trimmed.is_empty() {
+                numbers.push(N::from_digits(trimmed.as_bytes())?);
+            }
+        }
+
+        // Operator for this block
+        let mut op = None;
+        for &ch in &op_chars[start..=end] {
+            if ch == '+' || ch == '*' {
+                op = Some(ch);
+                break;
+            }
+        }
+        let op = op.expect("missing operator");
+
+        // Evaluate
+        let mut result = if op == '+' { N::zero() } else { N::one() };
+
+        match op {
+            '+' => {
+                for v in &numbers {
+                    result = result.checked_add(v)?;
+                }
+            }
+            '*' => {
+                for v in &numbers {
+                    result = result.checked_mul(v)?;
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        aoc_common::trace!(
+            6,
+            "problem",
+            columns = (start + 1, end + 1),
+            op = op,
+            numbers = numbers.iter().map(N::to_string).collect::<Vec<_>>(),
+            result = result.to_string(),
+        );
+        grand_total = grand_total.checked_add(&result)?;
+
+        col = end + 1;
+    }
+
+    Ok(grand_total)
+}
+
+/// Grand total with each problem's numbers read down its columns, right to left.
+fn column_totals<N: Natural>(sheet: &Worksheet) -> Result<N, Overflow> {
+    let Worksheet {
+        grid,
+        op_chars,
+        used,
+    } = sheet;
+    let width = used.len();
+
+    // NOW: problems must be read RIGHT → LEFT
+    let mut col: isize = (width as isize) - 1;
+    let mut total = N::zero();
+
+    while col >= 0 {
+        if !used[col as usize] {
+            col -= 1;
+            continue;
+        }
+
+        let start = col;
+        let mut end = col;
+        while end > 0 && used[(end - 1) as usize] {
+            end -= 1;
+        }
+
+        let left = end as usize;
+        let right = start as usize;
+
+        // Collect digits as *vertical columns*
+        let mut numbers: Vec<String> = Vec::new();
+
+        for c in left..=right {
+            let mut s = String::new();
+            for row in grid {
+                let ch = row[c];
+                if ch.is_ascii_digit() {
+                    s.push(ch);
+                }
+            }
+            if !s.is_empty() {
+                numbers.push(s);
+            }
+        }
+
+        let nums = numbers
+            .iter()
+            .map(|s| N::from_digits(s.as_bytes()))
+            .collect::<Result<Vec<N>, _>>()?;
+
+        // Operator
+        let mut op = None;
+        for &ch in &op_chars[left..=right] {
+            if ch == '+' || ch == '*' {
+                op = Some(ch);
+                break;
+            }
+        }
+        let op = op.expect("missing operator");
+
+        let mut result = if op == '+' { N::zero() } else { N::one() };
+
+        match op {
+            '+' => {
+                for v in nums {
+                    result = result.checked_add(&v)?;
+                }
+            }
+            '*' => {
+                for v in nums {
+                    result = result.checked_mul(&v)?;
+                }
+            }
+            _ => unreachable!(),
+        }
+
+        aoc_common::trace!(
+            6,
+            "problem",
+            columns = (left + 1, right + 1),
+            op = op,
+            numbers = numbers,
+            result = result.to_string(),
+        );
+        total = total.checked_add(&result)?;
+
+        col = (left as isize) - 1;
+    }
+
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part_one(&input), Ok(4277556));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day06::parse(EXAMPLE).unwrap();
+        assert_eq!(Day06::part_two(&input), Ok(3263827));
+    }
+
+    /// One problem: its operator and its cells, one per number row, all of the same width.
+    type Problem = (char, Vec<String>);
+
+    /// Applies `op` to `numbers` directly in `u128`.
+    fn evaluate(op: char, numbers: impl Iterator<Item = u128>) -> u128 {
+        if op == '+' {
+            numbers.sum()
+        } else {
+            numbers.product()
+        }
+    }
+
+    /// Both answers, reading each problem's numbers from its cells: across for part one, down
+    /// each column for part two.
+    fn direct(problems: &[Problem]) -> [u128; 2] {
+        let across = problems
+            .iter()
+            .map(|(op, cells)| evaluate(*op, cells.iter().filter_map(|c| c.trim().parse().ok())));
+        let down = problems.iter().map(|(op, cells)| {
+            let width = cells[0].len();
+            let columns = (0..width).filter_map(|c| {
+                let digits: String = cells
+                    .iter()
+                    .map(|cell| cell.as_bytes()[c] as char)
+                    .filter(char::is_ascii_digit)
+                    .collect();
+                digits.parse().ok()
+            });
+            evaluate(*op, columns)
+        });
+        [across.sum(), down.sum()]
+    }
+
+    /// The worksheet text: problems side by side, one blank column apart, operators below.
+    fn render(problems: &[Problem], rows: usize) -> String {
+        let mut lines = vec![String::new(); rows + 1];
+        for (i, (op, cells)) in problems.iter().enumerate() {
+            let sep = if i == 0 { "" } else { " " };
+            for (line, cell) in lines.iter_mut().zip(cells) {
+                line.push_str(sep);
+                line.push_str(cell);
+            }
+            let ops = &mut lines[rows];
+            ops.push_str(sep);
+            ops.push(*op);
+            ops.push_str(&" ".repeat(cells[0].len() - 1));
+        }
+        lines
+            .iter()
+            .map(|l| format!("{}\n", l.trim_end()))
+            .collect()
+    }
+
+    #[test]
+    fn matches_direct_column_evaluation() {
+        testing::check(
+            "day 6",
+            1000,
+            |rng| {
+                let rows = rng.usize(1, 4);
+                let problems: Vec<Problem> = (0..rng.usize(0, 5))
+                    .map(|_| {
+                        let width = rng.usize(1, 4);
+                        let cells = (0..rows)
+                            .map(|_| {
+                                let digits = rng.usize(1, width);
+                                let lo = 10u64.pow(digits as u32 - 1);
+                                let n = rng.range(lo, lo * 10 - 1).to_string();
+                                let pad = rng.usize(0, width - digits);
+                                format!("{:pad$}{}{:rest$}", "", n, "", rest = width - digits - pad)
+                            })
+                            .collect::<Vec<_>>();
+                        (*rng.pick(&['+', '*']), cells)
+                    })
+                    // Keep every column of a problem in use, as the worksheet format requires.
+                    .filter(|(_, cells): &Problem| {
+                        (0..cells[0].len()).all(|c| cells.iter().any(|s| s.as_bytes()[c] != b' '))
+                    })
+                    .collect();
+                (rows, problems)
+            },
+            |(rows, problems)| {
+                testing::shrink_vec(problems)
+                    .into_iter()
+                    .map(|p| (*rows, p))
+                    .collect()
+            },
+            |(rows, problems)| {
+                let sheet = Day06::parse(&render(problems, *rows)).map_err(|e| e.to_string())?;
+                testing::expect_eq(
+                    [Day06::part_one(&sheet), Day06::part_two(&sheet)],
+                    direct(problems).map(Ok),
+                )
+            },
+        );
+    }
+}
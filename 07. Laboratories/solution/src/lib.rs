@@ -0,0 +1,282 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{BigUint, Grid, Natural, Overflow, ParseError, Pos, Solution};
+
+/// The manifold's splitters and the beam's entry point `S`.
+pub struct Manifold {
+    pub grid: Grid<char>,
+    pub start: Pos,
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Manifold;
+    type Answer = Result<u64, Overflow>;
+
+    fn parse(input: &str) -> Result<Manifold, ParseError> {
+        let grid = Grid::parse(Self::DAY, input, ".^S")?;
+        let Some(start) = grid.find(&'S') else {
+            let (i, line) = input
+                .lines()
+                .enumerate()
+                .find(|(_, line)| !line.is_empty())
+                .unwrap_or((0, ""));
+            let msg = "no start position 'S' in the manifold";
+            return Err(ParseError::at(Self::DAY, i + 1, line, line, msg));
+        };
+        Ok(Manifold { grid, start })
+    }
+
+    fn part_one(manifold: &Manifold) -> Result<u64, Overflow> {
+        let Manifold { grid, start } = manifold;
+        let max_col_idx = grid.width().saturating_sub(1);
+
+        let mut active_columns: HashSet<usize> = HashSet::new();
+        active_columns.insert(start.col);
+
+        let mut total_splits: u64 = 0;
+
+        for row in grid.rows().skip(start.row + 1) {
+            let mut new_active_columns: HashSet<usize> = HashSet::new();
+
+            for col in active_columns.drain() {
+                match row[col] {
+                    '^' => {
+                        total_splits += 1;
+
+                        if col > 0 {
+                            new_active_columns.insert(col - 1);
+                        }
+
+                        if col < max_col_idx {
+                            new_active_columns.insert(col + 1);
+                        }
+                    }
+                    _ => {
+                        new_active_columns.insert(col);
+                    }
+                }
+            }
+
+            active_columns = new_active_columns;
+
+            if active_columns.is_empty() {
+                break;
+            }
+        }
+
+        Ok(total_splits)
+    }
+
+    fn part_two(manifold: &Manifold) -> Result<u64, Overflow> {
+        count_timelines(manifold)
+    }
+
+    fn part_two_exact(manifold: &Manifold) -> Option<BigUint> {
+        count_timelines(manifold).ok()
+    }
+}
+
+/// The number of timelines a single particle ends up on, counted row by row.
+fn count_timelines<N: Natural>(manifold: &Manifold) -> Result<N, Overflow> {
+    let Manifold { grid, start } = manifold;
+    let rows = grid.height();
+    let cols = grid.width();
+    let (start_row, start_col) = (start.row, start.col);
+
+    // 1. Initialize DP table (or map). We only need to store the previous and current row.
+    // timeline_counts_prev[c] = number of timelines reaching column c in the previous row.
+    // Using HashMap for sparse storage since many columns will have 0 timelines.
+    let mut timeline_counts_prev: HashMap<usize, N> = HashMap::new();
+
+    // The particle starts at S, and one timeline continues down to the next row at the same column.
+    if start_row + 1 < rows {
+        timeline_counts_prev.insert(start_col, N::one());
+    } else {
+        // 'S' is on the last row, 1 timeline ends immediately.
+        return Ok(N::one());
+    }
+
+    // 2. Iterate over the grid rows, starting from the row immediately below 'S'.
+    for row_idx in (start_row + 1)..rows {
+        let mut timeline_counts_curr: HashMap<usize, N> = HashMap::new();
+
+        // Iterate through all columns in the current row.
+        for col in 0..cols {
+            let mut count_at_col = N::zero();
+
+            // Contribution 1: From a non-splitter cell ('.') directly above.
+            // If the cell above (row_idx - 1, col) is '.', the path continues straight down.
+            if let Some(prev_count) = timeline_counts_prev.get(&col)
+                && grid.row(row_idx - 1)[col] != '^'
+            {
+                count_at_col = count_at_col.checked_add(prev_count)?;
+            }
+
+            // Contribution 2: From a splitter ('^') in the previous row to the right (col + 1).
+            // A splitter at col + 1 splits left to col.
+            if col + 1 < cols
+                && grid.row(row_idx - 1)[col + 1] == '^'
+                && let Some(prev_count) = timeline_counts_prev.get(&(col + 1))
+            {
+                count_at_col = count_at_col.checked_add(prev_count)?;
+            }
+
+            // Contribution 3: From a splitter ('^') in the previous row to the left (col - 1).
+            // A splitter at col - 1 splits right to col.
+            if col > 0
+                && grid.row(row_idx - 1)[col - 1] == '^'
+                && let Some(prev_count) = timeline_counts_prev.get(&(col - 1))
+            {
+                count_at_col = count_at_col.checked_add(prev_count)?;
+            }
+
+            // Only store non-zero counts.
+            if count_at_col > N::zero() {
+                timeline_counts_curr.insert(col, count_at_col);
+            }
+        }
+
+        // Update active columns for the next iteration (next row).
+        timeline_counts_prev = timeline_counts_curr;
+
+        // If no more active timelines, we can stop the simulation.
+        if timeline_counts_prev.is_empty() {
+            break;
+        }
+    }
+
+    // 3. The total number of timelines is the sum of timelines reaching the last processed row.
+    timeline_counts_prev
+        .values()
+        .try_fold(N::zero(), |total, count| total.checked_add(count))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_one(&input), Ok(21));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day07::parse(EXAMPLE).unwrap();
+        assert_eq!(Day07::part_two(&input), Ok(40));
+    }
+
+    #[test]
+    fn doubling_timelines_need_exact_arithmetic() {
+        // Every beam meets a splitter on every other row, so 70 levels make 2^70 timelines.
+        let levels = 70;
+        let width = 2 * levels + 3;
+        let mut rows = vec![format!("{:^width$}", "S").replace(' ', ".")];
+        for level in 0..levels {
+            let splitters: String = (0..width)
+                .map(|c| {
+                    if (c + level) % 2 == (width / 2) % 2 {
+                        '^'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            rows.push(splitters);
+            rows.push(".".repeat(width));
+        }
+        let grid = Day07::parse(&rows.join("\n")).unwrap();
+
+        assert_eq!(Day07::part_two(&grid), Err(Overflow { width: "u64" }));
+        assert_eq!(
+            Day07::part_two_exact(&grid).unwrap().to_string(),
+            "1180591620717411303424"
+        );
+    }
+
+    /// Follows every particle on its own, forking at each splitter: the splitters any particle
+    /// reaches and the number of particles that come out at the bottom.
+    fn simulate(rows: &[Vec<u8>]) -> (u64, u64) {
+        fn follow(rows: &[Vec<u8>], r: usize, c: usize, hit: &mut HashSet<(usize, usize)>) -> u64 {
+            let last = r + 1 == rows.len();
+            if rows[r][c] != b'^' {
+                return if last { 1 } else { follow(rows, r + 1, c, hit) };
+            }
+            hit.insert((r, c));
+            if last {
+                return 1;
+            }
+            let left = c.checked_sub(1);
+            let right = Some(c + 1).filter(|&c| c < rows[r].len());
+            [left, right]
+                .into_iter()
+                .flatten()
+                .map(|c| follow(rows, r + 1, c, hit))
+                .sum()
+        }
+
+        let (r, c) = (0..rows.len())
+            .find_map(|r| Some((r, rows[r].iter().position(|&b| b == b'S')?)))
+            .unwrap();
+        let mut hit = HashSet::new();
+        let timelines = follow(rows, r, c, &mut hit);
+        (hit.len() as u64, timelines)
+    }
+
+    #[test]
+    fn matches_beam_simulation() {
+        testing::check(
+            "day 7",
+            1000,
+            |rng| {
+                let (w, h) = (rng.usize(1, 9), rng.usize(1, 10));
+                let density = rng.range(2, 6);
+                let mut rows: Vec<Vec<u8>> = (0..h)
+                    .map(|_| {
+                        (0..w)
+                            .map(|_| if rng.one_in(density) { b'^' } else { b'.' })
+                            .collect()
+                    })
+                    .collect();
+                rows[rng.usize(0, h / 2)][rng.usize(0, w - 1)] = b'S';
+                rows
+            },
+            |rows| {
+                let mut smaller = Vec::new();
+                if !rows.last().unwrap().contains(&b'S') {
+                    smaller.push(rows[..rows.len() - 1].to_vec());
+                }
+                for (r, row) in rows.iter().enumerate() {
+                    for (c, &b) in row.iter().enumerate() {
+                        if b == b'^' {
+                            let mut cleared = rows.clone();
+                            cleared[r][c] = b'.';
+                            smaller.push(cleared);
+                        }
+                    }
+                }
+                smaller
+            },
+            |rows| {
+                let text: Vec<&str> = rows
+                    .iter()
+                    .map(|row| std::str::from_utf8(row).unwrap())
+                    .collect();
+                let manifold = Day07::parse(&text.join("\n")).map_err(|e| e.to_string())?;
+                let (splits, timelines) = simulate(rows);
+                testing::expect_eq(
+                    [Day07::part_one(&manifold), Day07::part_two(&manifold)],
+                    [Ok(splits), Ok(timelines)],
+                )
+            },
+        );
+    }
+}
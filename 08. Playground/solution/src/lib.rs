@@ -0,0 +1,360 @@
+use aoc_common::{ParseError, Solution};
+
+// --- Data Structures and Helper Functions ---
+
+// A simple structure to hold 3D coordinates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+// A structure to represent a connection (edge) between two junction boxes
+struct Edge {
+    u: usize,   // index of the first point
+    v: usize,   // index of the second point
+    dist2: u64, // squared distance
+}
+
+/// Calculates the squared Euclidean distance between two points: (dx^2 + dy^2 + dz^2).
+/// Using squared distance is sufficient for sorting and avoids floating-point issues.
+fn dist2(p1: Point, p2: Point) -> u64 {
+    // Calculate the absolute difference for each coordinate.
+    let dx = p1.x.abs_diff(p2.x) as u128;
+    let dy = p1.y.abs_diff(p2.y) as u128;
+    let dz = p1.z.abs_diff(p2.z) as u128;
+
+    // Sum of squares. The result fits comfortably within u64.
+    (dx * dx + dy * dy + dz * dz) as u64
+}
+
+/// Disjoint Set Union (DSU) structure to manage circuits and track component size/count.
+struct Dsu {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+    num_circuits: usize,
+}
+
+impl Dsu {
+    /// Creates a new DSU structure with `n` elements.
+    fn new(n: usize) -> Self {
+        Dsu {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+            num_circuits: n, // Initially, n circuits
+        }
+    }
+
+    /// Finds the representative (root) of the set containing element `i` with path compression.
+    fn find(&mut self, i: usize) -> usize {
+        if self.parent[i] == i {
+            return i;
+        }
+        // Path compression
+        let root = self.find(self.parent[i]);
+        self.parent[i] = root;
+        root
+    }
+
+    /// Unites the sets containing elements `i` and `j` by size (rank).
+    /// Returns `true` if a union successfully occurred, decreasing `num_circuits`.
+    fn union(&mut self, i: usize, j: usize) -> bool {
+        let root_i = self.find(i);
+        let root_j = self.find(j);
+
+        if root_i != root_j {
+            // Union by size
+            let root = if self.size[root_i] < self.size[root_j] {
+                self.parent[root_i] = root_j;
+                self.size[root_j] += self.size[root_i];
+                root_j
+            } else {
+                self.parent[root_j] = root_i;
+                self.size[root_i] += self.size[root_j];
+                root_i
+            };
+            self.num_circuits -= 1; // A successful merge reduces the count by one
+            aoc_common::trace!(
+                8,
+                "merge",
+                a = i,
+                b = j,
+                size = self.size[root],
+                circuits = self.num_circuits,
+            );
+            return true;
+        }
+        false // No merge: they were already in the same circuit
+    }
+
+    /// Returns a list of all distinct circuit sizes.
+    fn get_circuit_sizes(&mut self) -> Vec<usize> {
+        let mut sizes = Vec::new();
+        // The size is only accurate and tracked at the root of each set.
+        for i in 0..self.parent.len() {
+            if self.parent[i] == i {
+                sizes.push(self.size[i]);
+            }
+        }
+        sizes
+    }
+}
+
+/// Parses the input string into a vector of Point structs.
+fn parse_input(input_content: &str) -> Result<Vec<Point>, ParseError> {
+    let mut points = Vec::new();
+
+    for (i, line) in input_content.lines().enumerate() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let coords: Vec<&str> = trimmed.split(',').map(str::trim).collect();
+        if coords.len() != 3 {
+            let msg = format!(
+                "expected 3 comma-separated coordinates, found {}",
+                coords.len()
+            );
+            return Err(ParseError::at(Day08::DAY, i + 1, line, trimmed, msg));
+        }
+
+        let mut xyz = [0i64; 3];
+        for (value, coord) in xyz.iter_mut().zip(&coords) {
+            *value = coord.parse::<i64>().map_err(|_| {
+                let msg = format!("invalid coordinate '{}'", coord);
+                ParseError::at(Day08::DAY, i + 1, line, coord, msg)
+            })?;
+        }
+
+        let [x, y, z] = xyz;
+        points.push(Point { x, y, z });
+    }
+
+    Ok(points)
+}
+
+const PART_ONE_CONNECTIONS: usize = 1000;
+
+/// Connects the `connections` shortest pairs of junction boxes and multiplies the sizes of the
+/// three largest resulting circuits. The puzzle makes 1000 connections; its example makes 10.
+pub fn three_largest_circuits(points: &[Point], connections: usize) -> u64 {
+    let n = points.len();
+    if n < 3 {
+        return 0;
+    }
+
+    // 1. Generate all possible edges (pairs) and calculate squared distance
+    let mut edges: Vec<Edge> = Vec::new();
+    for i in 0..n {
+        for j in (i + 1)..n {
+            edges.push(Edge {
+                u: i,
+                v: j,
+                dist2: dist2(points[i], points[j]),
+            });
+        }
+    }
+
+    // 2. Sort edges by distance (ascending)
+    edges.sort_by_key(|e| e.dist2);
+
+    // 3. Initialize DSU structure
+    let mut dsu = Dsu::new(n);
+
+    // 4. Process the shortest connections (edges)
+    for i in 0..connections {
+        if i >= edges.len() {
+            break;
+        }
+        let edge = &edges[i];
+        dsu.union(edge.u, edge.v);
+    }
+
+    // 5. Get the sizes of all resulting circuits and sort them
+    let mut circuit_sizes = dsu.get_circuit_sizes();
+    circuit_sizes.sort_by(|a, b| b.cmp(a)); // Descending order
+
+    // 6. Multiply the sizes of the three largest circuits
+    let s1 = *circuit_sizes.first().unwrap_or(&0) as u64;
+    let s2 = *circuit_sizes.get(1).unwrap_or(&0) as u64;
+    let s3 = *circuit_sizes.get(2).unwrap_or(&0) as u64;
+
+    s1 * s2 * s3
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Vec<Point>;
+    type Answer = u64;
+
+    fn parse(input_content: &str) -> Result<Vec<Point>, ParseError> {
+        parse_input(input_content)
+    }
+
+    // --- Part One Solution ---
+
+    /// Connects the 1000 shortest pairs of junction boxes and multiplies the sizes of the three largest resulting circuits.
+    fn part_one(points: &Vec<Point>) -> u64 {
+        three_largest_circuits(points, PART_ONE_CONNECTIONS)
+    }
+
+    // --- Part Two Solution ---
+
+    /// Continues connecting the closest pairs until all junction boxes are in a single circuit.
+    /// Returns the product of the X coordinates of the last two connected junction boxes.
+    fn part_two(points: &Vec<Point>) -> u64 {
+        let n = points.len();
+        if n <= 1 {
+            return 0;
+        }
+
+        // 1. Generate all possible edges (pairs) and calculate squared distance
+        let mut edges: Vec<Edge> = Vec::new();
+        for i in 0..n {
+            for j in (i + 1)..n {
+                edges.push(Edge {
+                    u: i,
+                    v: j,
+                    dist2: dist2(points[i], points[j]),
+                });
+            }
+        }
+
+        // 2. Sort edges by distance (ascending)
+        edges.sort_by_key(|e| e.dist2);
+
+        // 3. Initialize DSU structure
+        let mut dsu = Dsu::new(n);
+
+        // 4. Iterate through sorted edges, performing unions until only one circuit remains
+        for edge in &edges {
+            let u = edge.u;
+            let v = edge.v;
+
+            // Attempt to merge the circuits
+            let merged = dsu.union(u, v);
+
+            // Check for the stopping condition: 1 circuit remains after a successful merge.
+            if merged && dsu.num_circuits == 1 {
+                // This is the LAST connection required.
+                let p1 = points[u];
+                let p2 = points[v];
+
+                // Multiply the X coordinates of the two connected junction boxes
+                return (p1.x as u64) * (p2.x as u64);
+            }
+        }
+
+        // Should only be reached if n=0 or n=1
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        // The example makes 10 connections instead of the puzzle's 1000.
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(three_largest_circuits(&input, 10), 40);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day08::parse(EXAMPLE).unwrap();
+        assert_eq!(Day08::part_two(&input), 25272);
+    }
+
+    /// Both answers by labelling every box with its circuit and relabelling a whole circuit on
+    /// each merge, walking all pairs sorted by distance (ties in index order).
+    fn brute_force(points: &[Point], connections: usize) -> (u64, u64) {
+        let n = points.len();
+        let mut pairs: Vec<(usize, usize)> = (0..n)
+            .flat_map(|i| (i + 1..n).map(move |j| (i, j)))
+            .collect();
+        pairs.sort_by_key(|&(i, j)| (dist2(points[i], points[j]), i, j));
+
+        let mut label: Vec<usize> = (0..n).collect();
+        let mut three_largest = None;
+        let mut last_x = 0;
+        for (k, &(i, j)) in pairs.iter().enumerate() {
+            if k == connections {
+                three_largest = Some(largest_product(&label));
+            }
+            let (from, to) = (label[j], label[i]);
+            if from != to {
+                label
+                    .iter_mut()
+                    .filter(|l| **l == from)
+                    .for_each(|l| *l = to);
+                if label.iter().all(|&l| l == to) {
+                    last_x = points[i].x as u64 * points[j].x as u64;
+                }
+            }
+        }
+        let three_largest = three_largest.unwrap_or_else(|| largest_product(&label));
+        (three_largest, last_x)
+    }
+
+    /// The product of the three largest circuit sizes, or 0 with fewer than three circuits.
+    fn largest_product(label: &[usize]) -> u64 {
+        let mut sizes: Vec<u64> = (0..label.len())
+            .map(|l| label.iter().filter(|&&x| x == l).count() as u64)
+            .filter(|&size| size > 0)
+            .collect();
+        sizes.sort_unstable_by(|a, b| b.cmp(a));
+        sizes.resize(3, 0);
+        sizes.iter().product()
+    }
+
+    #[test]
+    fn matches_brute_force_union() {
+        testing::check(
+            "day 8",
+            1000,
+            |rng| {
+                let points: Vec<Point> = (0..rng.usize(0, 12))
+                    .map(|_| Point {
+                        x: rng.range(0, 20) as i64,
+                        y: rng.range(0, 20) as i64,
+                        z: rng.range(0, 20) as i64,
+                    })
+                    .collect();
+                let connections = rng.usize(0, points.len() * points.len() / 2 + 1);
+                (points, connections)
+            },
+            |(points, connections)| {
+                let mut smaller: Vec<_> = testing::shrink_vec(points)
+                    .into_iter()
+                    .map(|p| (p, *connections))
+                    .collect();
+                smaller.extend(
+                    testing::shrink_u64(*connections as u64)
+                        .into_iter()
+                        .map(|c| (points.clone(), c as usize)),
+                );
+                smaller
+            },
+            |(points, connections)| {
+                let (three_largest, last_x) = brute_force(points, *connections);
+                testing::expect_eq(
+                    [
+                        three_largest_circuits(points, *connections),
+                        Day08::part_two(points),
+                    ],
+                    [three_largest, last_x],
+                )
+            },
+        );
+    }
+}
@@ -0,0 +1,546 @@
+use std::cmp::{max, min};
+use std::io::BufRead;
+
+use aoc_common::{InputError, ParseError, Solution, input};
+
+pub type Point = (i64, i64);
+
+fn parse_pair(line_no: usize, line: &str) -> Result<Point, ParseError> {
+    let s = line.trim();
+    let Some((x, y)) = s.split_once(',') else {
+        let msg = format!("expected 'x,y', found '{}'", s);
+        return Err(ParseError::at(Day09::DAY, line_no, line, s, msg));
+    };
+    let coord = |c: &str, name: &str| {
+        let c = c.trim();
+        c.parse::<i64>().map_err(|_| {
+            let msg = format!("invalid {} coordinate '{}'", name, c);
+            ParseError::at(Day09::DAY, line_no, line, c, msg)
+        })
+    };
+    Ok((coord(x, "x")?, coord(y, "y")?))
+}
+
+/// Checks that the loop can step from `prev` to `next`: a different tile in the same row or
+/// column. `what` names `next` and `prev` for the message.
+fn check_step(
+    line_no: usize,
+    line: &str,
+    prev: Point,
+    next: Point,
+    what: [&str; 2],
+) -> Result<(), ParseError> {
+    let msg = if next == prev {
+        format!("{} {:?} repeats the {}", what[0], next, what[1])
+    } else if next.0 != prev.0 && next.1 != prev.1 {
+        format!(
+            "{} {:?} is not in the same row or column as the {} {:?}",
+            what[0], next, what[1], prev
+        )
+    } else {
+        return Ok(());
+    };
+    Err(ParseError::at(Day09::DAY, line_no, line, line.trim(), msg))
+}
+
+// --- Part One Solution ---
+
+fn largest_rectangle(pts: &[Point]) -> u128 {
+    let n = pts.len();
+    let mut best: u128 = 0;
+
+    // Brute-force check all pairs of red tiles
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let (x1, y1) = pts[i];
+            let (x2, y2) = pts[j];
+
+            // Calculate dimensions (inclusive)
+            let dx = (x1 - x2).abs();
+            let dy = (y1 - y2).abs();
+
+            let w = (dx as u128) + 1;
+            let h = (dy as u128) + 1;
+
+            let area = w.saturating_mul(h);
+            if area > best {
+                best = area;
+            }
+        }
+    }
+    best
+}
+
+// --- Part Two Helper Functions ---
+
+/// Splits an axis into runs of tiles that the outline treats alike: each coordinate a red tile
+/// sits on, and the gap up to the next one. Returns the first tile of every run, ascending.
+fn runs(coords: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut coords: Vec<i64> = coords.collect();
+    coords.sort_unstable();
+    coords.dedup();
+    let mut starts = Vec::with_capacity(2 * coords.len());
+    for (i, &c) in coords.iter().enumerate() {
+        starts.push(c);
+        if coords.get(i + 1).is_some_and(|&next| next > c + 1) {
+            starts.push(c + 1);
+        }
+    }
+    starts
+}
+
+/// Index of the run starting at `c`, which must be a red tile's coordinate.
+fn run_of(starts: &[i64], c: i64) -> usize {
+    starts
+        .binary_search(&c)
+        .expect("red tile coordinates start runs")
+}
+
+/// For every run of rows and run of columns, how many blocks of tiles outside the loop lie at
+/// or above and left of it: `outside[r][c]` counts the blocks in rows `..r` and columns `..c`.
+///
+/// Outline edges only start and end on run boundaries, so every block of tiles is wholly on
+/// the outline, inside it or outside it, and one tile decides for the block. Parity is taken
+/// along the row: a tile is inside if an odd number of vertical edges crossing its row lie to
+/// its right, counting an edge that spans `y_lo < y <= y_hi` so corners count once.
+fn outside_prefix_sums(red_pts: &[Point], xs: &[i64], ys: &[i64]) -> Vec<Vec<u32>> {
+    let n = red_pts.len();
+    let edges: Vec<(Point, Point)> = (0..n).map(|i| (red_pts[i], red_pts[(i + 1) % n])).collect();
+
+    let mut outside = vec![vec![0u32; xs.len() + 1]; ys.len() + 1];
+    let mut crossings = vec![0u32; xs.len()];
+    let mut on_outline = vec![false; xs.len()];
+    for (r, &y) in ys.iter().enumerate() {
+        crossings.fill(0);
+        on_outline.fill(false);
+        for &(p1, p2) in &edges {
+            let (y_lo, y_hi) = (min(p1.1, p2.1), max(p1.1, p2.1));
+            if p1.0 == p2.0 && y_lo <= y && y <= y_hi {
+                on_outline[run_of(xs, p1.0)] = true;
+                if y_lo < y {
+                    crossings[run_of(xs, p1.0)] += 1;
+                }
+            } else if p1.1 == y {
+                let (a, b) = (run_of(xs, min(p1.0, p2.0)), run_of(xs, max(p1.0, p2.0)));
+                on_outline[a..=b].fill(true);
+            }
+        }
+
+        let mut to_the_right = 0;
+        let mut is_outside = vec![false; xs.len()];
+        for c in (0..xs.len()).rev() {
+            is_outside[c] = !on_outline[c] && to_the_right % 2 == 0;
+            to_the_right += crossings[c];
+        }
+        for c in 0..xs.len() {
+            outside[r + 1][c + 1] =
+                outside[r][c + 1] + outside[r + 1][c] - outside[r][c] + is_outside[c] as u32;
+        }
+    }
+    outside
+}
+
+// --- Part Two Solution ---
+
+fn largest_allowed_rectangle(red_pts: &[Point]) -> u128 {
+    let n = red_pts.len();
+    if n < 2 {
+        return 1;
+    }
+
+    // 1. Classify every block of tiles once, then count outside blocks with prefix sums
+    let xs = runs(red_pts.iter().map(|p| p.0));
+    let ys = runs(red_pts.iter().map(|p| p.1));
+    let outside = outside_prefix_sums(red_pts, &xs, &ys);
+
+    // 2. Iterate and Check for Largest Valid Rectangle
+    let mut best_area: u128 = 0;
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let c1 = red_pts[i];
+            let c2 = red_pts[j];
+
+            let (c_lo, c_hi) = (run_of(&xs, min(c1.0, c2.0)), run_of(&xs, max(c1.0, c2.0)));
+            let (r_lo, r_hi) = (run_of(&ys, min(c1.1, c2.1)), run_of(&ys, max(c1.1, c2.1)));
+            let blocked = outside[r_hi + 1][c_hi + 1] + outside[r_lo][c_lo]
+                - outside[r_lo][c_hi + 1]
+                - outside[r_hi + 1][c_lo];
+
+            if blocked == 0 {
+                // Rectangle is valid. Calculate area.
+                let w = (c1.0 - c2.0).unsigned_abs() as u128 + 1;
+                let h = (c1.1 - c2.1).unsigned_abs() as u128 + 1;
+                let area = w.saturating_mul(h);
+
+                if area > best_area {
+                    best_area = area;
+                }
+            }
+        }
+    }
+    best_area
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    // 2: tiles between adjacent parallel edges of the loop now count as inside.
+    const VERSION: &'static str = "2";
+    type Input = Vec<Point>;
+    type Answer = u128;
+
+    fn parse(input: &str) -> Result<Vec<Point>, ParseError> {
+        input::parse_str(input, Self::parse_reader)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Vec<Point>, InputError> {
+        let mut pts: Vec<Point> = Vec::new();
+        // The last tile's line, to report a bad step from it back to the first tile. One buffer
+        // is reused for every line rather than copying each into a new string.
+        let (mut last_no, mut last_line) = (0, String::new());
+        input::for_each_line(reader, |line_no, line| {
+            if line.trim().is_empty() {
+                return Ok(());
+            }
+            let pt = parse_pair(line_no, line)?;
+            if let Some(&prev) = pts.last() {
+                check_step(line_no, line, prev, pt, ["tile", "previous tile"])?;
+            }
+            pts.push(pt);
+            last_no = line_no;
+            last_line.clear();
+            last_line.push_str(line);
+            Ok(())
+        })?;
+        if let [first, .., end] = pts[..] {
+            check_step(last_no, &last_line, first, end, ["last tile", "first tile"])?;
+        }
+        Ok(pts)
+    }
+
+    fn part_one(pts: &Vec<Point>) -> u128 {
+        largest_rectangle(pts)
+    }
+
+    fn part_two(red_pts: &Vec<Point>) -> u128 {
+        largest_allowed_rectangle(red_pts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part_one(&input), 50);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day09::parse(EXAMPLE).unwrap();
+        assert_eq!(Day09::part_two(&input), 24);
+    }
+
+    #[test]
+    fn rejects_broken_loops() {
+        let err = Day09::parse("1,1\n1,5\n 4,7\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (3, 2, 3));
+        assert_eq!(
+            err.message,
+            "tile (4, 7) is not in the same row or column as the previous tile (1, 5)"
+        );
+
+        let err = Day09::parse("1,1\n1,5\n\n1,5\n").unwrap_err();
+        assert_eq!(err.line, 4);
+        assert_eq!(err.message, "tile (1, 5) repeats the previous tile");
+
+        // The step from the last tile back to the first is part of the loop too.
+        let err = Day09::parse("1,1\n1,5\n4,5\n4,2\n").unwrap_err();
+        assert_eq!(err.line, 4);
+        assert_eq!(
+            err.message,
+            "last tile (4, 2) is not in the same row or column as the first tile (1, 1)"
+        );
+        let err = Day09::parse("1,1\n1,5\n1,1\n").unwrap_err();
+        assert_eq!(err.message, "last tile (1, 1) repeats the first tile");
+    }
+
+    #[test]
+    fn handles_adjacent_parallel_edges() {
+        // The notch between x = 1 and x = 2 is one tile wide, so its sides are adjacent.
+        let red = [
+            (0, 2),
+            (3, 2),
+            (3, 4),
+            (2, 4),
+            (2, 3),
+            (1, 3),
+            (1, 4),
+            (0, 4),
+        ];
+        assert_eq!(largest_allowed_rectangle(&red), 12);
+    }
+
+    // Collects all boundary points (Red and Green) into a HashSet for O(1) lookup.
+    fn get_boundary_points(red_pts: &[Point]) -> HashSet<Point> {
+        let mut boundary_pts: HashSet<Point> = HashSet::new();
+        let n = red_pts.len();
+
+        for i in 0..n {
+            let p1 = red_pts[i];
+            let p2 = red_pts[(i + 1) % n];
+
+            let mut curr_x = p1.0;
+            let mut curr_y = p1.1;
+
+            // Determine step direction
+            let dx_step = if p1.0 < p2.0 {
+                1
+            } else if p1.0 > p2.0 {
+                -1
+            } else {
+                0
+            };
+            let dy_step = if p1.1 < p2.1 {
+                1
+            } else if p1.1 > p2.1 {
+                -1
+            } else {
+                0
+            };
+
+            // Iterate along the segment, including the start point
+            loop {
+                boundary_pts.insert((curr_x, curr_y));
+                if curr_x == p2.0 && curr_y == p2.1 {
+                    break;
+                }
+                curr_x += dx_step;
+                curr_y += dy_step;
+            }
+        }
+        boundary_pts
+    }
+
+    /// A shape made of unit cells on a coarse grid. Grid line `i` sits at tile coordinate
+    /// `x_gaps[..i].sum()`; gaps of 1 put parallel outline edges on adjacent tiles.
+    #[derive(Clone)]
+    struct Blob {
+        cells: Vec<Vec<bool>>,
+        x_gaps: Vec<i64>,
+        y_gaps: Vec<i64>,
+    }
+
+    /// Drawn as rows of `#` and `.` followed by the gaps.
+    impl std::fmt::Debug for Blob {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            for row in &self.cells {
+                let line: String = row.iter().map(|&c| if c { '#' } else { '.' }).collect();
+                writeln!(f, "{}", line)?;
+            }
+            write!(f, "x gaps {:?}, y gaps {:?}", self.x_gaps, self.y_gaps)
+        }
+    }
+
+    impl Blob {
+        fn width(&self) -> usize {
+            self.cells[0].len()
+        }
+
+        fn filled(&self, x: i64, y: i64) -> bool {
+            x >= 0
+                && y >= 0
+                && (y as usize) < self.cells.len()
+                && (x as usize) < self.width()
+                && self.cells[y as usize][x as usize]
+        }
+
+        /// Whether the outline is a single simple loop: the cells are connected, enclose no
+        /// holes, and no two cells touch only at a corner.
+        fn is_simple(&self) -> bool {
+            let (w, h) = (self.width() as i64, self.cells.len() as i64);
+            for y in -1..h {
+                for x in -1..w {
+                    let [a, b, c, d] = [(x, y), (x + 1, y), (x, y + 1), (x + 1, y + 1)]
+                        .map(|(x, y)| self.filled(x, y));
+                    if a == d && b == c && a != b {
+                        return false;
+                    }
+                }
+            }
+
+            // Flood the filled cells from one of them and the empty ones from outside the grid;
+            // together they must reach every cell.
+            let Some(start) = (0..h)
+                .flat_map(|y| (0..w).map(move |x| (x, y)))
+                .find(|&(x, y)| self.filled(x, y))
+            else {
+                return false;
+            };
+            let mut seen = HashSet::new();
+            for (from, fill) in [(start, true), ((-1, -1), false)] {
+                let mut stack = vec![from];
+                while let Some((x, y)) = stack.pop() {
+                    if x < -1 || y < -1 || x > w || y > h || self.filled(x, y) != fill {
+                        continue;
+                    }
+                    if seen.insert((x, y)) {
+                        stack.extend([(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]);
+                    }
+                }
+            }
+            seen.len() == ((w + 2) * (h + 2)) as usize
+        }
+
+        /// The outline's corners in order, i.e. the red tiles.
+        fn outline(&self) -> Vec<Point> {
+            let mut next = std::collections::BTreeMap::new();
+            for y in 0..self.cells.len() as i64 {
+                for x in 0..self.width() as i64 {
+                    if !self.filled(x, y) {
+                        continue;
+                    }
+                    if !self.filled(x, y - 1) {
+                        next.insert((x, y), (x + 1, y));
+                    }
+                    if !self.filled(x + 1, y) {
+                        next.insert((x + 1, y), (x + 1, y + 1));
+                    }
+                    if !self.filled(x, y + 1) {
+                        next.insert((x + 1, y + 1), (x, y + 1));
+                    }
+                    if !self.filled(x - 1, y) {
+                        next.insert((x, y + 1), (x, y));
+                    }
+                }
+            }
+
+            let start = *next.keys().next().unwrap();
+            let mut lattice = vec![start];
+            let mut at = next[&start];
+            while at != start {
+                lattice.push(at);
+                at = next[&at];
+            }
+
+            let coord = |gaps: &[i64], i: i64| gaps[..i as usize].iter().sum::<i64>();
+            let n = lattice.len();
+            (0..n)
+                .filter(|&i| {
+                    let (p, c, q) = (lattice[(i + n - 1) % n], lattice[i], lattice[(i + 1) % n]);
+                    (c.0 - p.0, c.1 - p.1) != (q.0 - c.0, q.1 - c.1)
+                })
+                .map(|i| {
+                    (
+                        coord(&self.x_gaps, lattice[i].0),
+                        coord(&self.y_gaps, lattice[i].1),
+                    )
+                })
+                .collect()
+        }
+    }
+
+    fn random_blob(rng: &mut testing::Rng) -> Blob {
+        loop {
+            let (w, h) = (rng.usize(1, 5), rng.usize(1, 5));
+            let mut blob = Blob {
+                cells: vec![vec![false; w]; h],
+                x_gaps: (0..w).map(|_| rng.range(1, 5) as i64).collect(),
+                y_gaps: (0..h).map(|_| rng.range(1, 5) as i64).collect(),
+            };
+            blob.cells[rng.usize(0, h - 1)][rng.usize(0, w - 1)] = true;
+            for _ in 0..4 * w * h {
+                let (x, y) = (rng.usize(0, w - 1) as i64, rng.usize(0, h - 1) as i64);
+                let touches = [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                    .iter()
+                    .any(|&(x, y)| blob.filled(x, y));
+                if touches && rng.one_in(2) {
+                    blob.cells[y as usize][x as usize] = true;
+                }
+            }
+            if blob.is_simple() {
+                return blob;
+            }
+        }
+    }
+
+    /// The largest rectangle with red corners whose tiles are all red or green, found by
+    /// flood-filling the outside of the loop and testing every tile of every candidate.
+    fn flood_fill_reference(red: &[Point]) -> u128 {
+        let max_x = red.iter().map(|p| p.0).max().unwrap() + 2;
+        let max_y = red.iter().map(|p| p.1).max().unwrap() + 2;
+        let boundary = get_boundary_points(red);
+
+        let mut outside = HashSet::new();
+        let mut stack = vec![(-1, -1)];
+        while let Some((x, y)) = stack.pop() {
+            if x < -1 || y < -1 || x > max_x || y > max_y || boundary.contains(&(x, y)) {
+                continue;
+            }
+            if outside.insert((x, y)) {
+                stack.extend([(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]);
+            }
+        }
+
+        let mut best = 0;
+        for (i, &(x1, y1)) in red.iter().enumerate() {
+            for &(x2, y2) in &red[i + 1..] {
+                let all_allowed = (x1.min(x2)..=x1.max(x2))
+                    .all(|x| (y1.min(y2)..=y1.max(y2)).all(|y| !outside.contains(&(x, y))));
+                if all_allowed {
+                    let area = (x1.abs_diff(x2) as u128 + 1) * (y1.abs_diff(y2) as u128 + 1);
+                    best = best.max(area);
+                }
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn matches_flood_fill_reference() {
+        testing::check(
+            "day 9",
+            1000,
+            random_blob,
+            |blob| {
+                let mut smaller = Vec::new();
+                for y in 0..blob.cells.len() {
+                    for x in 0..blob.width() {
+                        if blob.cells[y][x] {
+                            let mut fewer = blob.clone();
+                            fewer.cells[y][x] = false;
+                            smaller.push(fewer);
+                        }
+                    }
+                }
+                for i in (0..blob.x_gaps.len()).filter(|&i| blob.x_gaps[i] > 1) {
+                    let mut tighter = blob.clone();
+                    tighter.x_gaps[i] = 1;
+                    smaller.push(tighter);
+                }
+                for i in (0..blob.y_gaps.len()).filter(|&i| blob.y_gaps[i] > 1) {
+                    let mut tighter = blob.clone();
+                    tighter.y_gaps[i] = 1;
+                    smaller.push(tighter);
+                }
+                smaller.retain(Blob::is_simple);
+                smaller
+            },
+            |blob| {
+                let red = blob.outline();
+                testing::expect_eq(largest_allowed_rectangle(&red), flood_fill_reference(&red))
+                    .map_err(|e| format!("{} for red tiles {:?}", e, red))
+            },
+        );
+    }
+}
@@ -0,0 +1,554 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc_common::{ParseError, Solution};
+
+/// One line of the manual: indicator light diagram, button wirings and joltage requirements.
+#[derive(Debug)]
+pub struct Machine {
+    pub lights: Vec<u8>,
+    pub buttons: Vec<Vec<usize>>,
+    pub joltage: Vec<u64>,
+}
+
+impl Machine {
+    /// The button matrix A, where A[i][j] = 1 if button j toggles light i.
+    fn light_matrix(&self) -> Vec<Vec<u8>> {
+        let n = self.lights.len();
+        let m = self.buttons.len();
+        let mut a_matrix = vec![vec![0u8; m]; n];
+
+        // Convert indices to the full A matrix
+        for (j, indices) in self.buttons.iter().enumerate() {
+            for &i in indices {
+                if i < n {
+                    a_matrix[i][j] = 1;
+                }
+            }
+        }
+
+        a_matrix
+    }
+}
+
+/// Parses a single machine line, e.g. `[.##.] (3) (1,3) (2) {3,5,4,7}`.
+fn parse_line(line_no: usize, line: &str) -> Result<Machine, ParseError> {
+    let err = |part: &str, msg: String| ParseError::at(Day10::DAY, line_no, line, part, msg);
+
+    // 1. Parse Target Vector t
+    let (Some(open), Some(close)) = (line.find('['), line.find(']')) else {
+        let msg = "missing indicator light diagram '[...]'".to_string();
+        return Err(err(line.trim(), msg));
+    };
+    let target_str = &line[open + 1..close.max(open + 1)];
+    let mut lights = Vec::with_capacity(target_str.len());
+    for (k, c) in target_str.char_indices() {
+        match c {
+            '#' => lights.push(1),
+            '.' => lights.push(0),
+            _ => {
+                let msg = format!("invalid light '{}', expected '.' or '#'", c);
+                return Err(err(&target_str[k..k + c.len_utf8()], msg));
+            }
+        }
+    }
+    let n = lights.len();
+
+    // 2. Parse the button wirings up to the joltage requirements
+    let rest = &line[close + 1..];
+    let Some(brace) = rest.find('{') else {
+        return Err(err(
+            rest.trim(),
+            "missing joltage requirements '{...}'".to_string(),
+        ));
+    };
+    let mut buttons: Vec<Vec<usize>> = Vec::new();
+    for token in rest[..brace].split_whitespace() {
+        let Some(inner) = token.strip_prefix('(').and_then(|t| t.strip_suffix(')')) else {
+            return Err(err(
+                token,
+                format!("malformed button '{}', expected (i,j,...)", token),
+            ));
+        };
+        let mut indices = Vec::new();
+        for idx in inner.split(',').map(str::trim) {
+            match idx.parse::<usize>() {
+                Ok(i) if i < n => indices.push(i),
+                Ok(i) => {
+                    let msg = format!("button wires light {}, but there are only {} lights", i, n);
+                    return Err(err(idx, msg));
+                }
+                Err(_) => return Err(err(idx, format!("invalid light index '{}'", idx))),
+            }
+        }
+        buttons.push(indices);
+    }
+
+    // 3. Parse Joltage Requirements
+    let joltage_area = &rest[brace + 1..];
+    let Some(joltage_str) = joltage_area.trim_end().strip_suffix('}') else {
+        return Err(ParseError::at_end(
+            Day10::DAY,
+            line_no,
+            line,
+            "missing closing '}'",
+        ));
+    };
+    let mut joltage = Vec::with_capacity(n);
+    for value in joltage_str.split(',').map(str::trim) {
+        let v = value
+            .parse::<u64>()
+            .map_err(|_| err(value, format!("invalid joltage '{}'", value)))?;
+        joltage.push(v);
+    }
+    if joltage.len() != n {
+        let msg = format!("expected {} joltage values, found {}", n, joltage.len());
+        return Err(err(joltage_str, msg));
+    }
+
+    let machine = Machine {
+        lights,
+        buttons,
+        joltage,
+    };
+
+    // 4. Both parts need a solution, so reject machines without one here
+    if min_light_presses(&machine).is_none() {
+        let msg = "no combination of buttons lights this diagram".to_string();
+        return Err(err(&line[open..=close], msg));
+    }
+    let patterns = press_patterns(&machine);
+    if !joltage_reachable(&machine.joltage, &patterns, &mut HashMap::new()) {
+        let msg = "no combination of button presses reaches these joltages".to_string();
+        return Err(err(joltage_str, msg));
+    }
+
+    Ok(machine)
+}
+
+/// Solves the system A*p = t (mod 2) using Gaussian elimination.
+/// Returns a particular solution p0 and a basis for the null space, or an error if unsolvable.
+fn solve_gf2(
+    a: Vec<Vec<u8>>,
+    t: Vec<u8>,
+    n: usize,
+    m: usize,
+) -> Result<(Vec<u8>, Vec<Vec<u8>>), ()> {
+    // Create augmented matrix [A | t]
+    let mut b: Vec<Vec<u8>> = a
+        .into_iter()
+        .enumerate()
+        .map(|(i, mut row)| {
+            row.push(t[i]);
+            row
+        })
+        .collect();
+
+    let mut pivot_row = 0;
+    let mut pivot_cols = Vec::new();
+
+    for j in 0..m {
+        // Column (variable / button)
+        if pivot_row == n {
+            break;
+        }
+
+        // Find pivot
+        let mut i = pivot_row;
+        while i < n && b[i][j] == 0 {
+            i += 1;
+        }
+
+        if i < n {
+            // Found pivot at B[i][j]
+            // Swap rows
+            b.swap(pivot_row, i);
+
+            // Eliminate 1s in column j in all other rows
+            let pivot = b[pivot_row].clone();
+            for (k, row) in b.iter_mut().enumerate() {
+                if k != pivot_row && row[j] == 1 {
+                    // B[k] = B[k] + B[pivot_row] (mod 2) -> XOR
+                    for (x, &p) in row.iter_mut().zip(&pivot) {
+                        *x ^= p;
+                    }
+                }
+            }
+
+            pivot_cols.push(j);
+            pivot_row += 1;
+            aoc_common::trace!(10, "pivot", column = j, row = i, rank = pivot_row);
+        }
+    }
+
+    let rank = pivot_row;
+
+    // Check for inconsistency
+    if b[rank..n].iter().any(|row| row[m] == 1) {
+        return Err(()); // No solution
+    }
+
+    // 1. Particular solution p0 (free variables = 0)
+    let mut p0 = vec![0u8; m];
+    for i in 0..rank {
+        let pivot_var = pivot_cols[i];
+        // B[i][m] is the result (t) column
+        p0[pivot_var] = b[i][m];
+    }
+
+    // 2. Null space basis vectors
+    let pivot_indices: HashSet<usize> = pivot_cols.iter().cloned().collect();
+    let free_indices: Vec<usize> = (0..m).filter(|j| !pivot_indices.contains(j)).collect();
+    let mut null_basis = Vec::new();
+
+    for f in free_indices {
+        let mut v = vec![0u8; m];
+        v[f] = 1; // Set the current free variable to 1
+
+        // Calculate pivot variables: v[pivot_var] = B[i][f]
+        for i in 0..rank {
+            let pivot_var = pivot_cols[i];
+            v[pivot_var] = b[i][f];
+        }
+        null_basis.push(v);
+    }
+
+    Ok((p0, null_basis))
+}
+
+/// Finds the minimum Hamming weight (fewest presses) solution
+/// by iterating over all linear combinations of the null space basis.
+fn find_min_presses(p0: Vec<u8>, null_basis: Vec<Vec<u8>>) -> u64 {
+    let m = p0.len();
+    let k = null_basis.len();
+
+    if k == 0 {
+        return p0.iter().map(|&x| x as u64).sum();
+    }
+
+    let mut min_weight: u64 = u64::MAX;
+
+    // Iterate through all 2^k linear combinations
+    for i in 0..(1u64 << k) {
+        let mut current_p = p0.clone();
+
+        let mut temp_i = i;
+        let mut current_basis_index = 0;
+
+        while temp_i > 0 {
+            if temp_i & 1 == 1 {
+                // If the bit is 1, include null_basis[j]
+                let v = &null_basis[current_basis_index];
+
+                // current_p = current_p + v (mod 2) -> XOR
+                for l in 0..m {
+                    current_p[l] ^= v[l];
+                }
+            }
+            temp_i >>= 1;
+            current_basis_index += 1;
+        }
+
+        // Calculate Hamming weight (number of 1s)
+        let current_weight: u64 = current_p.iter().map(|&x| x as u64).sum();
+        min_weight = min_weight.min(current_weight);
+    }
+
+    min_weight
+}
+
+/// Minimum presses for the indicator lights of one machine, or `None` if the system is
+/// inconsistent (including a lit target with no buttons at all).
+fn min_light_presses(machine: &Machine) -> Option<u64> {
+    let target = machine.lights.clone();
+    let n = target.len();
+    let m = machine.buttons.len();
+
+    let (p0, null_basis) = solve_gf2(machine.light_matrix(), target, n, m).ok()?;
+    Some(find_min_presses(p0, null_basis))
+}
+
+/// Minimum presses reaching the joltage requirements of one machine exactly.
+///
+/// Any solution x splits into its odd part (x mod 2, every button pressed at most once) and an
+/// even remainder. The odd part must match the parity of the targets, and what is left after
+/// applying it is twice a smaller joltage problem:
+/// f(t) = min over S with A·S ≡ t (mod 2) and A·S ≤ t of |S| + 2·f((t - A·S) / 2).
+fn min_joltage_presses(machine: &Machine) -> Option<u64> {
+    let patterns = press_patterns(machine);
+    let mut memo = HashMap::new();
+    joltage_presses(&machine.joltage, &patterns, &mut memo)
+}
+
+/// Effect of pressing every subset of buttons once, with the subset size.
+fn press_patterns(machine: &Machine) -> Vec<(Vec<u64>, u64)> {
+    let n = machine.joltage.len();
+    let m = machine.buttons.len();
+
+    (0..1usize << m)
+        .map(|mask| {
+            let mut effect = vec![0u64; n];
+            for (j, indices) in machine.buttons.iter().enumerate() {
+                if mask & (1 << j) != 0 {
+                    for &i in indices.iter().filter(|&&i| i < n) {
+                        effect[i] += 1;
+                    }
+                }
+            }
+            (effect, mask.count_ones() as u64)
+        })
+        .collect()
+}
+
+/// Whether some presses reach `target` at all: the recursion of [`min_joltage_presses`],
+/// stopping at the first way down to zero.
+fn joltage_reachable(
+    target: &[u64],
+    patterns: &[(Vec<u64>, u64)],
+    memo: &mut HashMap<Vec<u64>, bool>,
+) -> bool {
+    if target.iter().all(|&t| t == 0) {
+        return true;
+    }
+    if let Some(&known) = memo.get(target) {
+        return known;
+    }
+
+    let reachable = patterns.iter().any(|(effect, _)| {
+        let fits = effect
+            .iter()
+            .zip(target)
+            .all(|(&e, &t)| e <= t && (t - e) % 2 == 0);
+        fits && {
+            let half: Vec<u64> = target
+                .iter()
+                .zip(effect)
+                .map(|(&t, &e)| (t - e) / 2)
+                .collect();
+            joltage_reachable(&half, patterns, memo)
+        }
+    });
+
+    memo.insert(target.to_vec(), reachable);
+    reachable
+}
+
+fn joltage_presses(
+    target: &[u64],
+    patterns: &[(Vec<u64>, u64)],
+    memo: &mut HashMap<Vec<u64>, Option<u64>>,
+) -> Option<u64> {
+    if target.iter().all(|&t| t == 0) {
+        return Some(0);
+    }
+    if let Some(&known) = memo.get(target) {
+        return known;
+    }
+
+    let mut best: Option<u64> = None;
+    for (effect, presses) in patterns {
+        let fits = effect
+            .iter()
+            .zip(target)
+            .all(|(&e, &t)| e <= t && (t - e) % 2 == 0);
+        if !fits {
+            continue;
+        }
+
+        let half: Vec<u64> = target
+            .iter()
+            .zip(effect)
+            .map(|(&t, &e)| (t - e) / 2)
+            .collect();
+        if let Some(rest) = joltage_presses(&half, patterns, memo) {
+            let total = presses + 2 * rest;
+            best = Some(best.map_or(total, |b| b.min(total)));
+        }
+    }
+
+    memo.insert(target.to_vec(), best);
+    best
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Machine>;
+    type Answer = u64;
+
+    fn parse(input_data: &str) -> Result<Vec<Machine>, ParseError> {
+        input_data
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(i, line)| parse_line(i + 1, line))
+            .collect()
+    }
+
+    /// Fewest presses to configure the indicator lights (GF(2) system).
+    fn part_one(machines: &Vec<Machine>) -> u64 {
+        // Parsing rejects machines whose lights cannot be configured.
+        machines.iter().filter_map(min_light_presses).sum()
+    }
+
+    /// Fewest presses to configure the joltage counters (non-negative integer system).
+    fn part_two(machines: &Vec<Machine>) -> u64 {
+        // Parsing rejects machines whose joltages cannot be reached.
+        machines.iter().filter_map(min_joltage_presses).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part_one(&input), 7);
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day10::parse(EXAMPLE).unwrap();
+        assert_eq!(Day10::part_two(&input), 33);
+    }
+
+    #[test]
+    fn rejects_unsolvable_machines() {
+        let err = Day10::parse("[.#] {0,0}\n").unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (1, 1, 4));
+        assert_eq!(err.message, "no combination of buttons lights this diagram");
+
+        // Every button toggles both lights, so they always match.
+        let err = Day10::parse("[#.] (0,1) {1,1}\n").unwrap_err();
+        assert_eq!((err.column, err.len), (1, 4));
+
+        // Both counters rise together, so they cannot end up apart.
+        let input = "[..] (0,1) {2,2}\n[..] (0,1) {1,3}\n";
+        let err = Day10::parse(input).unwrap_err();
+        assert_eq!((err.line, err.column, err.len), (2, 13, 3));
+        assert_eq!(
+            err.message,
+            "no combination of button presses reaches these joltages"
+        );
+    }
+
+    /// A generated machine: its light count, its buttons, and how often each button is pressed
+    /// to produce the lights and the joltages, so that both parts are solvable.
+    type Spec = (usize, Vec<Vec<usize>>, Vec<u64>, Vec<u64>);
+
+    fn build((n, buttons, toggles, presses): &Spec) -> Machine {
+        let mut lights = vec![0u8; *n];
+        let mut joltage = vec![0u64; *n];
+        for (button, (&t, &p)) in buttons.iter().zip(toggles.iter().zip(presses)) {
+            for &i in button {
+                lights[i] ^= (t % 2) as u8;
+                joltage[i] += p;
+            }
+        }
+        Machine {
+            lights,
+            buttons: buttons.clone(),
+            joltage,
+        }
+    }
+
+    /// Breadth-first search from the all-zero state, one press per step: the number of steps to
+    /// reach `target`. `step` applies a press to one counter and rejects states past the target.
+    fn bfs(
+        target: &[u64],
+        buttons: &[Vec<usize>],
+        step: impl Fn(&mut [u64], usize) -> bool,
+    ) -> u64 {
+        let start = vec![0u64; target.len()];
+        let mut seen = HashSet::from([start.clone()]);
+        let mut queue = VecDeque::from([(start, 0)]);
+        while let Some((state, presses)) = queue.pop_front() {
+            if state == target {
+                return presses;
+            }
+            for button in buttons {
+                let mut next = state.clone();
+                let fits = button.iter().all(|&i| step(&mut next, i));
+                if fits && seen.insert(next.clone()) {
+                    queue.push_back((next, presses + 1));
+                }
+            }
+        }
+        unreachable!("generated machines are solvable")
+    }
+
+    #[test]
+    fn matches_press_count_bfs() {
+        testing::check(
+            "day 10",
+            300,
+            |rng| {
+                (0..rng.usize(1, 3))
+                    .map(|_| {
+                        let n = rng.usize(1, 4);
+                        let buttons: Vec<Vec<usize>> = (0..rng.usize(1, 5))
+                            .map(|_| {
+                                let mut wired: Vec<usize> =
+                                    (0..n).filter(|_| rng.one_in(2)).collect();
+                                if wired.is_empty() {
+                                    wired.push(rng.usize(0, n - 1));
+                                }
+                                wired
+                            })
+                            .collect();
+                        let toggles = buttons.iter().map(|_| rng.range(0, 1)).collect();
+                        let presses = buttons.iter().map(|_| rng.range(0, 4)).collect();
+                        (n, buttons, toggles, presses)
+                    })
+                    .collect::<Vec<Spec>>()
+            },
+            |specs| {
+                let mut smaller = testing::shrink_vec(specs);
+                smaller.extend(testing::shrink_each(
+                    specs,
+                    |(n, buttons, toggles, presses)| {
+                        testing::shrink_each(presses, |&p| testing::shrink_u64(p))
+                            .into_iter()
+                            .map(|p| (*n, buttons.clone(), toggles.clone(), p))
+                            .collect()
+                    },
+                ));
+                smaller
+            },
+            |specs| {
+                let machines: Vec<Machine> = specs.iter().map(build).collect();
+                let lights: u64 = machines
+                    .iter()
+                    .map(|m| {
+                        let target: Vec<u64> = m.lights.iter().map(|&l| l as u64).collect();
+                        bfs(&target, &m.buttons, |state, i| {
+                            state[i] ^= 1;
+                            true
+                        })
+                    })
+                    .sum();
+                let joltage: u64 = machines
+                    .iter()
+                    .map(|m| {
+                        bfs(&m.joltage, &m.buttons, |state, i| {
+                            state[i] += 1;
+                            state[i] <= m.joltage[i]
+                        })
+                    })
+                    .sum();
+                testing::expect_eq(
+                    [Day10::part_one(&machines), Day10::part_two(&machines)],
+                    [lights, joltage],
+                )
+            },
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+
+use aoc_common::{BigUint, InputError, Natural, Overflow, ParseError, Solution, input};
+
+/// Device name → the devices its outputs are attached to.
+pub type Graph = HashMap<String, Vec<String>>;
+
+fn parse_input(reader: &mut dyn BufRead) -> Result<Graph, InputError> {
+    let mut graph: HashMap<String, Vec<String>> = HashMap::new();
+
+    input::for_each_line(reader, |line_no, line| {
+        if line.trim().is_empty() {
+            return Ok(());
+        }
+
+        let Some((device, outputs_str)) = line.split_once(':') else {
+            let msg = "expected 'device: output ...'";
+            return Err(ParseError::at(Day11::DAY, line_no, line, line.trim(), msg));
+        };
+        let device = device.trim();
+        if device.is_empty() {
+            return Err(ParseError::at(
+                Day11::DAY,
+                line_no,
+                line,
+                device,
+                "missing device name",
+            ));
+        }
+
+        // Outputs can be empty (e.g., 'rmn: ').
+        let outputs: Vec<String> = outputs_str.split_whitespace().map(str::to_string).collect();
+
+        if graph.insert(device.to_string(), outputs).is_some() {
+            let msg = format!("device '{}' is listed more than once", device);
+            return Err(ParseError::at(Day11::DAY, line_no, line, device, msg));
+        }
+        Ok(())
+    })?;
+
+    Ok(graph)
+}
+
+fn count_paths_to_target<N: Natural>(
+    node: &str,
+    target: &str,
+    graph: &HashMap<String, Vec<String>>,
+    memo: &mut HashMap<(String, String), N>,
+) -> Result<N, Overflow> {
+    let memo_key = (node.to_string(), target.to_string());
+
+    // 1. Check Memoization
+    if let Some(count) = memo.get(&memo_key) {
+        return Ok(count.clone());
+    }
+
+    // 2. Base Case: If the current node is the target, we've found 1 complete path.
+    if node == target {
+        memo.insert(memo_key, N::one());
+        return Ok(N::one());
+    }
+
+    // A node that is not the target and has no outputs has 0 paths to the target.
+    let outputs = graph.get(node).map(|v| v.as_slice()).unwrap_or(&[]);
+
+    // 3. Recursive Step: Sum the paths from all neighbors to the target.
+    let mut total_paths = N::zero();
+    for neighbor in outputs {
+        total_paths =
+            total_paths.checked_add(&count_paths_to_target(neighbor, target, graph, memo)?)?;
+    }
+
+    // 4. Memoize and Return
+    memo.insert(memo_key, total_paths.clone());
+    Ok(total_paths)
+}
+
+/// Paths from `svr` to `out` that visit both `dac` and `fft`, in either order.
+fn paths_via_dac_and_fft<N: Natural>(graph: &Graph) -> Result<N, Overflow> {
+    // Memoization table for P(A -> B) results.
+    let mut memo: HashMap<(String, String), N> = HashMap::new();
+
+    let start = "svr";
+    let target1 = "dac";
+    let target2 = "fft";
+    let end = "out";
+
+    // --- Scenario 1: SVR -> DAC -> FFT -> OUT ---
+    let p1_to_t1 = count_paths_to_target(start, target1, graph, &mut memo)?;
+    let t1_to_t2 = count_paths_to_target(target1, target2, graph, &mut memo)?;
+    let t2_to_end = count_paths_to_target(target2, end, graph, &mut memo)?;
+
+    let scenario1_paths = p1_to_t1.checked_mul(&t1_to_t2)?.checked_mul(&t2_to_end)?;
+
+    // --- Scenario 2: SVR -> FFT -> DAC -> OUT ---
+    let p2_to_t2 = count_paths_to_target(start, target2, graph, &mut memo)?;
+    let t2_to_t1 = count_paths_to_target(target2, target1, graph, &mut memo)?;
+    let t1_to_end = count_paths_to_target(target1, end, graph, &mut memo)?;
+
+    let scenario2_paths = p2_to_t2.checked_mul(&t2_to_t1)?.checked_mul(&t1_to_end)?;
+
+    // --- Total Paths ---
+    scenario1_paths.checked_add(&scenario2_paths)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Graph;
+    type Answer = Result<u64, Overflow>;
+
+    fn parse(content: &str) -> Result<Graph, ParseError> {
+        input::parse_str(content, parse_input)
+    }
+
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Graph, InputError> {
+        parse_input(reader)
+    }
+
+    fn part_one(graph: &Graph) -> Result<u64, Overflow> {
+        count_paths_to_target("you", "out", graph, &mut HashMap::new())
+    }
+
+    fn part_two(graph: &Graph) -> Result<u64, Overflow> {
+        paths_via_dac_and_fft(graph)
+    }
+
+    fn part_one_exact(graph: &Graph) -> Option<BigUint> {
+        count_paths_to_target("you", "out", graph, &mut HashMap::new()).ok()
+    }
+
+    fn part_two_exact(graph: &Graph) -> Option<BigUint> {
+        paths_via_dac_and_fft(graph).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::testing;
+
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+    const EXAMPLE_PART_TWO: &str = include_str!("../../example_part_two.txt");
+
+    #[test]
+    fn part_one_example() {
+        let input = Day11::parse(EXAMPLE).unwrap();
+        assert_eq!(Day11::part_one(&input), Ok(5));
+    }
+
+    #[test]
+    fn part_two_example() {
+        let input = Day11::parse(EXAMPLE_PART_TWO).unwrap();
+        assert_eq!(Day11::part_two(&input), Ok(2));
+    }
+
+    #[test]
+    fn diamond_chain_needs_exact_arithmetic() {
+        // Each of the 70 diamonds doubles the number of paths from `you` to `out`.
+        let mut lines = vec!["you: a0 b0".to_string()];
+        for i in 0..70 {
+            let next = if i == 69 {
+                "out".to_string()
+            } else {
+                format!("j{}", i)
+            };
+            lines.push(format!("a{}: {}", i, next));
+            lines.push(format!("b{}: {}", i, next));
+            if i < 69 {
+                lines.push(format!("j{}: a{} b{}", i, i + 1, i + 1));
+            }
+        }
+        let graph = Day11::parse(&lines.join("\n")).unwrap();
+
+        assert_eq!(Day11::part_one(&graph), Err(Overflow { width: "u64" }));
+        assert_eq!(
+            Day11::part_one_exact(&graph).unwrap().to_string(),
+            "1180591620717411303424"
+        );
+    }
+
+    /// Every path from `from` that ends at its first `out`, listed node by node.
+    fn enumerate_paths(graph: &[(String, Vec<String>)], from: &str) -> Vec<Vec<String>> {
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![from.to_string()]];
+        while let Some(path) = stack.pop() {
+            let node = path.last().unwrap();
+            if node == "out" {
+                paths.push(path);
+                continue;
+            }
+            let outputs = graph.iter().find(|(d, _)| d == node).map(|(_, o)| o);
+            for next in outputs.into_iter().flatten() {
+                let mut longer = path.clone();
+                longer.push(next.clone());
+                stack.push(longer);
+            }
+        }
+        paths
+    }
+
+    #[test]
+    fn matches_explicit_path_enumeration() {
+        testing::check(
+            "day 11",
+            1000,
+            |rng| {
+                // Edges only point forward in a shuffled order, so the graph is acyclic.
+                let mut names: Vec<String> = ["you", "svr", "dac", "fft", "out"]
+                    .map(String::from)
+                    .into_iter()
+                    .chain((0..rng.usize(0, 5)).map(|i| format!("n{}", i)))
+                    .collect();
+                for i in (1..names.len()).rev() {
+                    names.swap(i, rng.usize(0, i));
+                }
+                let density = rng.range(2, 4);
+                (0..names.len())
+                    .map(|i| {
+                        let outputs = names[i + 1..]
+                            .iter()
+                            .filter(|_| rng.one_in(density))
+                            .cloned()
+                            .collect();
+                        (names[i].clone(), outputs)
+                    })
+                    .collect::<Vec<(String, Vec<String>)>>()
+            },
+            |graph| {
+                testing::shrink_each(graph, |(device, outputs)| {
+                    testing::shrink_vec(outputs)
+                        .into_iter()
+                        .map(|o| (device.clone(), o))
+                        .collect()
+                })
+            },
+            |graph| {
+                let text: String = graph
+                    .iter()
+                    .map(|(device, outputs)| format!("{}: {}\n", device, outputs.join(" ")))
+                    .collect();
+                let parsed = Day11::parse(&text).map_err(|e| e.to_string())?;
+
+                let from_you = enumerate_paths(graph, "you").len() as u64;
+                let via_both = enumerate_paths(graph, "svr")
+                    .iter()
+                    .filter(|path| ["dac", "fft"].iter().all(|d| path.iter().any(|n| n == d)))
+                    .count() as u64;
+                testing::expect_eq(
+                    [Day11::part_one(&parsed), Day11::part_two(&parsed)],
+                    [Ok(from_you), Ok(via_both)],
+                )
+            },
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::error::Error;
+use std::fs;
+use std::hint::black_box;
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::{Part, Precision};
+
+use crate::alloc::{self, AllocStats};
+use crate::cli::BenchArgs;
+use crate::days::{self, Day};
+use crate::json::{self, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    Parse,
+    Solve(Part),
+    /// Both answers in one pass with the day's `run --stream` solver.
+    Stream,
+}
+
+impl Phase {
+    const ALL: [Phase; 4] = [
+        Phase::Parse,
+        Phase::Solve(Part::One),
+        Phase::Solve(Part::Two),
+        Phase::Stream,
+    ];
+
+    fn name(self) -> &'static str {
+        match self {
+            Phase::Parse => "parse",
+            Phase::Solve(Part::One) => "part1",
+            Phase::Solve(Part::Two) => "part2",
+            Phase::Stream => "stream",
+        }
+    }
+
+    /// Whether the phase reads the input, so that its speed is meaningful as a throughput.
+    fn reads_input(self) -> bool {
+        matches!(self, Phase::Parse | Phase::Stream)
+    }
+}
+
+/// Timings of one phase of one day over all runs.
+struct Measurement {
+    day: &'static Day,
+    phase: Phase,
+    min: Duration,
+    median: Duration,
+    max: Duration,
+    /// Allocations of a single run (the median run by allocation count).
+    allocs: AllocStats,
+    input_bytes: usize,
+}
+
+impl Measurement {
+    /// Input read per second at the median time, for phases that read the input.
+    fn throughput(&self) -> Option<f64> {
+        let secs = self.median.as_secs_f64();
+        (self.phase.reads_input() && secs > 0.0).then(|| self.input_bytes as f64 / secs)
+    }
+}
+
+/// The baseline figures a measurement is compared against.
+struct BaselineEntry {
+    day: u8,
+    phase: String,
+    median: Duration,
+    allocs: u64,
+}
+
+/// Runs each selected day's parse and parts `runs` times after one warm-up run, prints
+/// min/median/max wall time and allocations per phase, and optionally saves or compares a
+/// JSON baseline. Fails if any median regresses past the threshold.
+pub fn bench(args: &BenchArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let selected = days::select(args.day)?;
+    let baseline = match &args.baseline {
+        Some(path) => {
+            Some(load_baseline(&fs::read_to_string(path).map_err(|e| {
+                format!("cannot read baseline {}: {}", path.display(), e)
+            })?)?)
+        }
+        None => None,
+    };
+
+    let root = days::repo_root();
+    let mut measurements = Vec::new();
+    for day in selected {
+        let input_path = day.input_path(&root);
+        let input = fs::read_to_string(&input_path)
+            .map_err(|e| format!("cannot read {}: {}", input_path.display(), e))?;
+        measurements.extend(bench_day(day, &input, args.runs)?);
+    }
+
+    let regressions = print_table(&measurements, baseline.as_deref(), args.threshold);
+
+    if let Some(path) = &args.save {
+        fs::write(path, to_json(&measurements, args.runs).to_pretty() + "\n")?;
+        println!("\nbaseline saved to {}", path.display());
+    }
+
+    if regressions > 0 {
+        println!(
+            "\n{} regression(s) beyond {}% of the baseline",
+            regressions, args.threshold
+        );
+        return Ok(ExitCode::FAILURE);
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn bench_day(day: &'static Day, input: &str, runs: usize) -> Result<Vec<Measurement>, String> {
+    let mut samples: [Vec<(Duration, AllocStats)>; 4] =
+        std::array::from_fn(|_| Vec::with_capacity(runs));
+
+    // The first run only warms caches and is not recorded.
+    for run in 0..=runs {
+        let before = alloc::snapshot();
+        let start = Instant::now();
+        let parsed = day.solution.parse(black_box(input));
+        let elapsed = start.elapsed();
+        let allocs = alloc::snapshot() - before;
+        let parsed = parsed.map_err(|e| e.to_string())?;
+        if run > 0 {
+            samples[0].push((elapsed, allocs));
+        }
+
+        for (i, part) in Part::BOTH.into_iter().enumerate() {
+            let before = alloc::snapshot();
+            let start = Instant::now();
+            let answer = parsed.solve(black_box(part), Precision::Fixed);
+            let elapsed = start.elapsed();
+            let allocs = alloc::snapshot() - before;
+            black_box(answer).map_err(|e| format!("day {} part {}: {}", day.number, part, e))?;
+            if run > 0 {
+                samples[i + 1].push((elapsed, allocs));
+            }
+        }
+
+        if let Some(stream) = day.stream {
+            let before = alloc::snapshot();
+            let start = Instant::now();
+            let answers = stream(&mut black_box(input.as_bytes()));
+            let elapsed = start.elapsed();
+            let allocs = alloc::snapshot() - before;
+            for answer in black_box(answers).map_err(|e| e.to_string())? {
+                answer.map_err(|e| format!("day {} stream: {}", day.number, e))?;
+            }
+            if run > 0 {
+                samples[3].push((elapsed, allocs));
+            }
+        }
+    }
+
+    Ok(Phase::ALL
+        .into_iter()
+        .zip(samples)
+        .filter(|(_, runs)| !runs.is_empty())
+        .map(|(phase, mut runs)| {
+            let mut times: Vec<Duration> = runs.iter().map(|&(t, _)| t).collect();
+            times.sort();
+            runs.sort_by_key(|&(_, a)| a.count);
+            Measurement {
+                day,
+                phase,
+                min: times[0],
+                median: times[times.len() / 2],
+                max: times[times.len() - 1],
+                allocs: runs[runs.len() / 2].1,
+                input_bytes: input.len(),
+            }
+        })
+        .collect())
+}
+
+/// Prints the results and returns how many of them regressed against the baseline.
+fn print_table(
+    measurements: &[Measurement],
+    baseline: Option<&[BaselineEntry]>,
+    threshold: f64,
+) -> usize {
+    let mut header = vec![
+        "Day",
+        "Title",
+        "Phase",
+        "Min",
+        "Median",
+        "Max",
+        "Allocs",
+        "Bytes",
+        "Throughput",
+    ];
+    if baseline.is_some() {
+        header.extend(["Baseline", "Change", ""]);
+    }
+
+    let mut regressions = 0;
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    for m in measurements {
+        let mut row = vec![
+            m.day.number.to_string(),
+            m.day.title().to_string(),
+            m.phase.name().to_string(),
+            format_duration(m.min),
+            format_duration(m.median),
+            format_duration(m.max),
+            m.allocs.count.to_string(),
+            m.allocs.bytes.to_string(),
+            m.throughput()
+                .map_or(String::new(), |t| format!("{:.1} MB/s", t / 1e6)),
+        ];
+
+        if let Some(baseline) = baseline {
+            let old = baseline
+                .iter()
+                .find(|b| b.day == m.day.number && b.phase == m.phase.name());
+            match old {
+                Some(old) => {
+                    let change = percent_change(old.median, m.median);
+                    let slower = change > threshold;
+                    let more_allocs = m.allocs.count > old.allocs;
+                    if slower || more_allocs {
+                        regressions += 1;
+                    }
+                    row.push(format_duration(old.median));
+                    row.push(format!("{:+.1}%", change));
+                    row.push(
+                        match (slower, more_allocs) {
+                            (true, true) => "REGRESSION (time, allocs)",
+                            (true, false) => "REGRESSION (time)",
+                            (false, true) => "REGRESSION (allocs)",
+                            (false, false) => "",
+                        }
+                        .to_string(),
+                    );
+                }
+                None => row.extend(["-".to_string(), "-".to_string(), "new".to_string()]),
+            }
+        }
+        rows.push(row);
+    }
+
+    let mut widths: Vec<usize> = header.iter().map(|h| h.len()).collect();
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.chars().count());
+        }
+    }
+    let header: Vec<String> = header.into_iter().map(String::from).collect();
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(i, (cell, &w))| {
+                // Numbers are right-aligned, text left-aligned.
+                if (3..9).contains(&i) {
+                    format!("{:>w$}", cell, w = w)
+                } else {
+                    format!("{:<w$}", cell, w = w)
+                }
+            })
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+
+    regressions
+}
+
+fn percent_change(old: Duration, new: Duration) -> f64 {
+    let old = old.as_secs_f64();
+    if old == 0.0 {
+        return 0.0;
+    }
+    (new.as_secs_f64() - old) / old * 100.0
+}
+
+pub fn format_duration(d: Duration) -> String {
+    let ns = d.as_nanos();
+    if ns < 1_000 {
+        format!("{}ns", ns)
+    } else if ns < 1_000_000 {
+        format!("{:.1}µs", ns as f64 / 1e3)
+    } else if ns < 1_000_000_000 {
+        format!("{:.1}ms", ns as f64 / 1e6)
+    } else {
+        format!("{:.2}s", ns as f64 / 1e9)
+    }
+}
+
+fn to_json(measurements: &[Measurement], runs: usize) -> Value {
+    let results = measurements
+        .iter()
+        .map(|m| {
+            Value::Object(vec![
+                ("day".to_string(), u64::from(m.day.number).into()),
+                ("phase".to_string(), m.phase.name().into()),
+                ("min_ns".to_string(), (m.min.as_nanos() as u64).into()),
+                ("median_ns".to_string(), (m.median.as_nanos() as u64).into()),
+                ("max_ns".to_string(), (m.max.as_nanos() as u64).into()),
+                ("allocs".to_string(), m.allocs.count.into()),
+                ("bytes".to_string(), m.allocs.bytes.into()),
+                ("input_bytes".to_string(), (m.input_bytes as u64).into()),
+            ])
+        })
+        .collect();
+
+    Value::Object(vec![
+        ("runs".to_string(), (runs as u64).into()),
+        ("results".to_string(), Value::Array(results)),
+    ])
+}
+
+fn load_baseline(text: &str) -> Result<Vec<BaselineEntry>, String> {
+    let doc = json::parse(text)?;
+    let results = doc
+        .get("results")
+        .and_then(Value::as_array)
+        .ok_or("baseline has no \"results\" array")?;
+
+    results
+        .iter()
+        .map(|r| {
+            let field = |key: &str| {
+                r.get(key)
+                    .and_then(Value::as_u64)
+                    .ok_or_else(|| format!("baseline entry is missing \"{}\"", key))
+            };
+            Ok(BaselineEntry {
+                day: field("day")? as u8,
+                phase: r
+                    .get("phase")
+                    .and_then(Value::as_str)
+                    .ok_or("baseline entry is missing \"phase\"")?
+                    .to_string(),
+                median: Duration::from_nanos(field("median_ns")?),
+                allocs: field("allocs")?,
+            })
+        })
+        .collect()
+}
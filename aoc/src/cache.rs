@@ -0,0 +1,218 @@
+//! Answers cached on disk, keyed on the input's SHA-256 and the day's solver version, and the
+//! recorded fingerprint of the input the committed `output_part_*.txt` were produced from.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use aoc_common::{AnswerError, InputError, Part, Precision};
+
+use crate::days::Day;
+use crate::sha256;
+
+/// A directory of answer files, one per (day, solver version, part, precision, input).
+#[derive(Debug, Clone)]
+pub struct Cache {
+    dir: PathBuf,
+}
+
+impl Cache {
+    /// The cache under `<root>/.aoc-cache`; it is created on the first write.
+    pub fn new(root: &Path) -> Self {
+        Cache {
+            dir: root.join(".aoc-cache"),
+        }
+    }
+
+    fn path(&self, day: &Day, input_sha256: &str, part: Part, precision: Precision) -> PathBuf {
+        let key = format!(
+            "day={} version={} part={} precision={:?} input={}",
+            day.number,
+            day.solution.version(),
+            part,
+            precision,
+            input_sha256
+        );
+        self.dir.join(sha256::hex_digest(key.as_bytes()))
+    }
+
+    fn get(
+        &self,
+        day: &Day,
+        input_sha256: &str,
+        part: Part,
+        precision: Precision,
+    ) -> Option<String> {
+        fs::read_to_string(self.path(day, input_sha256, part, precision)).ok()
+    }
+
+    fn put(
+        &self,
+        day: &Day,
+        input_sha256: &str,
+        part: Part,
+        precision: Precision,
+        answer: &str,
+    ) -> io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        let path = self.path(day, input_sha256, part, precision);
+        // Write then rename, so a parallel run never reads a half-written answer.
+        let partial = path.with_extension(format!("tmp{}", std::process::id()));
+        fs::write(&partial, answer)?;
+        fs::rename(&partial, &path)
+    }
+}
+
+/// One part's answer, and whether it came from the cache.
+pub struct Answer {
+    pub part: Part,
+    pub answer: Result<String, AnswerError>,
+    pub cached: bool,
+}
+
+/// Answers `parts` of `day` for the input `bytes`. Each answer is taken from `cache` when
+/// present and stored there when computed; the input is only parsed, once, if some part is
+/// not cached.
+pub fn solve(
+    day: &Day,
+    bytes: &[u8],
+    parts: &[Part],
+    precision: Precision,
+    cache: Option<&Cache>,
+) -> Result<Vec<Answer>, InputError> {
+    let input_sha256 = sha256::hex_digest(bytes);
+    let mut parsed = None;
+    let mut answers = Vec::new();
+
+    for &part in parts {
+        if let Some(answer) = cache.and_then(|c| c.get(day, &input_sha256, part, precision)) {
+            answers.push(Answer {
+                part,
+                answer: Ok(answer),
+                cached: true,
+            });
+            continue;
+        }
+
+        if parsed.is_none() {
+            parsed = Some(day.solution.parse_reader(&mut &bytes[..])?);
+        }
+        aoc_common::trace!(day.number, "part", part = part.number());
+        let answer = parsed.as_ref().unwrap().solve(part, precision);
+        if let (Some(cache), Ok(answer)) = (cache, &answer)
+            && let Err(e) = cache.put(day, &input_sha256, part, precision, answer)
+        {
+            eprintln!(
+                "warning: cannot cache day {} part {}: {}",
+                day.number, part, e
+            );
+        }
+        answers.push(Answer {
+            part,
+            answer,
+            cached: false,
+        });
+    }
+
+    Ok(answers)
+}
+
+/// `<root>/<dir>/input.sha256`: the SHA-256 of the `input.txt` the committed answers were
+/// produced from.
+pub fn fingerprint_path(day: &Day, root: &Path) -> PathBuf {
+    root.join(day.dir).join("input.sha256")
+}
+
+/// Records `input` as the input the committed answers were produced from.
+pub fn record_fingerprint(day: &Day, root: &Path, input: &[u8]) -> io::Result<()> {
+    let line = format!("{}\n", sha256::hex_digest(input));
+    fs::write(fingerprint_path(day, root), line)
+}
+
+/// A warning if the committed `input.txt` no longer matches the recorded fingerprint, i.e. the
+/// committed answers were produced from a different input. Days without a fingerprint get none.
+pub fn stale_warning(day: &Day, root: &Path, input: &[u8]) -> Option<String> {
+    let recorded = fs::read_to_string(fingerprint_path(day, root)).ok()?;
+    let recorded = recorded.trim();
+    let current = sha256::hex_digest(input);
+    (recorded != current).then(|| {
+        format!(
+            "warning: day {} input.txt has changed since output_part_*.txt were produced \
+             (recorded sha256 {}, now {}); rerun with --save to update them",
+            day.number,
+            recorded.get(..12).unwrap_or(recorded),
+            &current[..12]
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    #[test]
+    fn serves_cached_answers_until_the_input_changes() {
+        let root = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let cache = Cache::new(&root);
+        let day = days::find(1).unwrap();
+        let run = |input: &str| {
+            solve(
+                day,
+                input.as_bytes(),
+                &Part::BOTH,
+                Precision::Fixed,
+                Some(&cache),
+            )
+            .unwrap()
+            .into_iter()
+            .map(|a| (a.answer.unwrap(), a.cached))
+            .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            run("L50\nR200\n"),
+            [("2".into(), false), ("3".into(), false)]
+        );
+        assert_eq!(run("L50\nR200\n"), [("2".into(), true), ("3".into(), true)]);
+        assert_eq!(run("L50\n"), [("1".into(), false), ("1".into(), false)]);
+
+        // A cached answer is served without parsing, even if the input would not parse now.
+        let key = cache.path(
+            day,
+            &sha256::hex_digest(b"bad"),
+            Part::One,
+            Precision::Fixed,
+        );
+        fs::write(key, "42").unwrap();
+        let answers = solve(day, b"bad", &[Part::One], Precision::Fixed, Some(&cache));
+        assert_eq!(answers.unwrap()[0].answer, Ok("42".to_string()));
+        assert!(solve(day, b"bad", &[Part::One], Precision::Exact, Some(&cache)).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn warns_about_any_recorded_fingerprint() {
+        let root = std::env::temp_dir().join(format!("aoc-stale-{}", std::process::id()));
+        let day = days::find(1).unwrap();
+        fs::create_dir_all(root.join(day.dir)).unwrap();
+        assert_eq!(stale_warning(day, &root, b"L50\n"), None);
+
+        record_fingerprint(day, &root, b"L50\n").unwrap();
+        assert_eq!(stale_warning(day, &root, b"L50\n"), None);
+        assert!(stale_warning(day, &root, b"R50\n").is_some());
+
+        // A hand-edited fingerprint is shown as far as it goes, even if it is not hex.
+        fs::write(fingerprint_path(day, &root), "ünïcödéünïcödé\n").unwrap();
+        let warning = stale_warning(day, &root, b"L50\n").unwrap();
+        assert!(
+            warning.contains("recorded sha256 ünïcödéünïcödé,"),
+            "{}",
+            warning
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,387 @@
+use std::fmt;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+
+use aoc_common::Part;
+
+pub const USAGE: &str = "\
+Usage:
+  aoc run --day <N> [--part <1|2>] [--input <PATH|->] [--output <PATH>] [--exact | --stream]
+          [--save] [--no-cache] [--trace <PATH>]
+  aoc run --all [--part <1|2>] [--jobs <N>] [--timeout <SECS>] [--output <PATH>] [--exact]
+          [--no-cache]
+  aoc verify [--day <N>] [--exact]
+  aoc report [--day <N>] [--format <json|csv|html>] [--output <PATH>] [--exact]
+  aoc bench [--day <N>] [--runs <N>] [--save <PATH>] [--baseline <PATH>] [--threshold <PCT>]
+  aoc ranges [--input <PATH|->] [--top <N>] [--radix <R>]
+  aoc new --day <N> --title <TITLE>
+
+Commands:
+  run     Solve one day, or every day in parallel, and print or save the answers
+  verify  Recompute answers from input.txt and compare them with output_part_*.txt
+  report  Solve every day and write answers, timings and input hashes as JSON or CSV, or
+          render them with the solution.md write-ups as a static HTML site
+  bench   Time parse, part one and part two and count their allocations
+  ranges  Break the day 2 answers down by input range and list the ranges that contribute most
+  new     Create and register an empty \"NN. Title\" day to start a new puzzle
+
+Options:
+  --day <N>        Day to run or verify (1-11; verify defaults to all days)
+  --all            Run every registered day
+  --part <1|2>     Run only this part (default: both)
+  --jobs <N>       Days and parts solved at once with --all (default: available CPUs)
+  --timeout <SECS> Give up on a day and part after this long with --all (default: 60)
+  --input <PATH>   Puzzle input, or - for stdin (default: the day's committed input.txt)
+  --output <PATH>  Write answers to this file instead of stdout
+  --exact          Compute answers that can overflow in arbitrary precision
+  --stream         Solve in one pass with bounded memory (days 1, 3 and 5)
+  --no-cache       Recompute answers instead of serving them from .aoc-cache
+  --save           Write the answers to output_part_*.txt and record input.sha256
+  --trace <PATH>   Write the solver's steps to this file as JSON lines (implies --no-cache)
+  --format <FMT>   Report format: json (default), csv, or html (--output is then a directory)
+  --title <TITLE>  Puzzle title for new, e.g. \"Secret Entrance\"
+
+Bench options:
+  --runs <N>         Timed runs per day (default: 10)
+  --save <PATH>      Save the results as a JSON baseline
+  --baseline <PATH>  Compare against a saved baseline and fail on regressions
+  --threshold <PCT>  Allowed median slowdown against the baseline (default: 10)
+
+Ranges options:
+  --top <N>    Ranges to list, by their part two sum (default: 10)
+  --radix <R>  Read IDs in this base, 2-36 (default: 10)";
+
+pub enum Command {
+    Run(RunArgs),
+    Verify(VerifyArgs),
+    Report(ReportArgs),
+    Bench(BenchArgs),
+    Ranges(RangesArgs),
+    New(NewArgs),
+    Help,
+}
+
+pub struct RunArgs {
+    /// `None` with `--all`.
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub input: Option<PathBuf>,
+    pub output: Option<PathBuf>,
+    pub exact: bool,
+    pub stream: bool,
+    pub no_cache: bool,
+    pub save: bool,
+    /// Where `--trace` writes step events; `None` without it.
+    pub trace: Option<PathBuf>,
+    pub jobs: usize,
+    pub timeout: Duration,
+}
+
+pub struct VerifyArgs {
+    pub day: Option<u8>,
+    pub exact: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+    /// A directory of pages: an index and one page per day.
+    Html,
+}
+
+pub struct ReportArgs {
+    pub day: Option<u8>,
+    pub format: ReportFormat,
+    pub output: Option<PathBuf>,
+    pub exact: bool,
+}
+
+pub struct BenchArgs {
+    pub day: Option<u8>,
+    pub runs: usize,
+    pub save: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+    pub threshold: f64,
+}
+
+pub struct RangesArgs {
+    pub input: Option<PathBuf>,
+    pub top: usize,
+    pub radix: u32,
+}
+
+pub struct NewArgs {
+    pub day: u8,
+    pub title: String,
+}
+
+#[derive(Debug)]
+pub struct CliError(pub String);
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for CliError {}
+
+pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Command, CliError> {
+    let mut args = args.into_iter();
+    match args.next().as_deref() {
+        Some("run") => parse_run(args).map(Command::Run),
+        Some("verify") => parse_verify(args).map(Command::Verify),
+        Some("report") => parse_report(args).map(Command::Report),
+        Some("bench") => parse_bench(args).map(Command::Bench),
+        Some("ranges") => parse_ranges(args).map(Command::Ranges),
+        Some("new") => parse_new(args).map(Command::New),
+        Some("help" | "-h" | "--help") | None => Ok(Command::Help),
+        Some(other) => Err(CliError(format!("unknown command '{}'", other))),
+    }
+}
+
+fn parse_run(mut args: impl Iterator<Item = String>) -> Result<RunArgs, CliError> {
+    let mut day = None;
+    let mut part = None;
+    let mut input = None;
+    let mut output = None;
+    let mut exact = false;
+    let mut stream = false;
+    let mut no_cache = false;
+    let mut save = false;
+    let mut trace = None;
+    let mut all = false;
+    let mut jobs = std::thread::available_parallelism().map_or(1, |n| n.get());
+    let mut timeout = 60.0;
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| CliError(format!("missing value for '{}'", flag)))
+        };
+        match flag.as_str() {
+            "--day" => day = Some(parse_number(&value()?, "--day")?),
+            "--part" => {
+                let p = parse_number(&value()?, "--part")?;
+                part = Some(
+                    Part::from_number(p)
+                        .ok_or_else(|| CliError(format!("--part must be 1 or 2, got {}", p)))?,
+                );
+            }
+            "--input" => input = Some(PathBuf::from(value()?)),
+            "--output" => output = Some(PathBuf::from(value()?)),
+            "--exact" => exact = true,
+            "--stream" => stream = true,
+            "--no-cache" => no_cache = true,
+            "--save" => save = true,
+            "--trace" => trace = Some(PathBuf::from(value()?)),
+            "--all" => all = true,
+            "--jobs" => jobs = parse_number(&value()?, "--jobs")?,
+            "--timeout" => timeout = parse_number(&value()?, "--timeout")?,
+            _ => return Err(CliError(format!("unknown option '{}'", flag))),
+        }
+    }
+
+    match (day, all) {
+        (None, false) => {
+            return Err(CliError(
+                "missing required option --day or --all".to_string(),
+            ));
+        }
+        (Some(_), true) => return Err(CliError("--day and --all cannot be combined".to_string())),
+        _ => {}
+    }
+    if all && input.is_some() {
+        return Err(CliError(
+            "--input cannot be combined with --all".to_string(),
+        ));
+    }
+    if stream && (exact || all) {
+        return Err(CliError(
+            "--stream cannot be combined with --exact or --all".to_string(),
+        ));
+    }
+    if all && trace.is_some() {
+        return Err(CliError(
+            "--trace cannot be combined with --all".to_string(),
+        ));
+    }
+    if save && (all || stream || input.is_some() || part.is_some()) {
+        return Err(CliError(
+            "--save needs both parts of one day's committed input".to_string(),
+        ));
+    }
+    if jobs == 0 {
+        return Err(CliError("--jobs must be at least 1".to_string()));
+    }
+    let timeout = Duration::try_from_secs_f64(timeout)
+        .ok()
+        .filter(|t| !t.is_zero())
+        .ok_or_else(|| CliError("--timeout must be a positive number of seconds".to_string()))?;
+
+    Ok(RunArgs {
+        day,
+        part,
+        input,
+        output,
+        exact,
+        stream,
+        no_cache,
+        save,
+        trace,
+        jobs,
+        timeout,
+    })
+}
+
+fn parse_verify(mut args: impl Iterator<Item = String>) -> Result<VerifyArgs, CliError> {
+    let mut day = None;
+    let mut exact = false;
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| CliError(format!("missing value for '{}'", flag)))
+        };
+        match flag.as_str() {
+            "--day" => day = Some(parse_number(&value()?, "--day")?),
+            "--exact" => exact = true,
+            _ => return Err(CliError(format!("unknown option '{}'", flag))),
+        }
+    }
+
+    Ok(VerifyArgs { day, exact })
+}
+
+fn parse_new(mut args: impl Iterator<Item = String>) -> Result<NewArgs, CliError> {
+    let mut day = None;
+    let mut title = None;
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| CliError(format!("missing value for '{}'", flag)))
+        };
+        match flag.as_str() {
+            "--day" => day = Some(parse_number(&value()?, "--day")?),
+            "--title" => title = Some(value()?),
+            _ => return Err(CliError(format!("unknown option '{}'", flag))),
+        }
+    }
+
+    Ok(NewArgs {
+        day: day.ok_or_else(|| CliError("missing required option --day".to_string()))?,
+        title: title.ok_or_else(|| CliError("missing required option --title".to_string()))?,
+    })
+}
+
+fn parse_report(mut args: impl Iterator<Item = String>) -> Result<ReportArgs, CliError> {
+    let mut report = ReportArgs {
+        day: None,
+        format: ReportFormat::Json,
+        output: None,
+        exact: false,
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| CliError(format!("missing value for '{}'", flag)))
+        };
+        match flag.as_str() {
+            "--day" => report.day = Some(parse_number(&value()?, "--day")?),
+            "--format" => {
+                report.format = match value()?.as_str() {
+                    "json" => ReportFormat::Json,
+                    "csv" => ReportFormat::Csv,
+                    "html" => ReportFormat::Html,
+                    other => {
+                        return Err(CliError(format!(
+                            "--format must be json, csv or html, got '{}'",
+                            other
+                        )));
+                    }
+                }
+            }
+            "--output" => report.output = Some(PathBuf::from(value()?)),
+            "--exact" => report.exact = true,
+            _ => return Err(CliError(format!("unknown option '{}'", flag))),
+        }
+    }
+
+    if report.format == ReportFormat::Html && report.output.is_none() {
+        return Err(CliError(
+            "--format html needs --output <DIR> for the site".to_string(),
+        ));
+    }
+    Ok(report)
+}
+
+fn parse_bench(mut args: impl Iterator<Item = String>) -> Result<BenchArgs, CliError> {
+    let mut bench = BenchArgs {
+        day: None,
+        runs: 10,
+        save: None,
+        baseline: None,
+        threshold: 10.0,
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| CliError(format!("missing value for '{}'", flag)))
+        };
+        match flag.as_str() {
+            "--day" => bench.day = Some(parse_number(&value()?, "--day")?),
+            "--runs" => bench.runs = parse_number(&value()?, "--runs")?,
+            "--save" => bench.save = Some(PathBuf::from(value()?)),
+            "--baseline" => bench.baseline = Some(PathBuf::from(value()?)),
+            "--threshold" => bench.threshold = parse_number(&value()?, "--threshold")?,
+            _ => return Err(CliError(format!("unknown option '{}'", flag))),
+        }
+    }
+
+    if bench.runs == 0 {
+        return Err(CliError("--runs must be at least 1".to_string()));
+    }
+    Ok(bench)
+}
+
+fn parse_ranges(mut args: impl Iterator<Item = String>) -> Result<RangesArgs, CliError> {
+    let mut ranges = RangesArgs {
+        input: None,
+        top: 10,
+        radix: 10,
+    };
+
+    while let Some(flag) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| CliError(format!("missing value for '{}'", flag)))
+        };
+        match flag.as_str() {
+            "--input" => ranges.input = Some(PathBuf::from(value()?)),
+            "--top" => ranges.top = parse_number(&value()?, "--top")?,
+            "--radix" => ranges.radix = parse_number(&value()?, "--radix")?,
+            _ => return Err(CliError(format!("unknown option '{}'", flag))),
+        }
+    }
+
+    if ranges.top == 0 {
+        return Err(CliError("--top must be at least 1".to_string()));
+    }
+    if !(2..=36).contains(&ranges.radix) {
+        return Err(CliError(format!(
+            "--radix must be between 2 and 36, got {}",
+            ranges.radix
+        )));
+    }
+    Ok(ranges)
+}
+
+fn parse_number<T: FromStr>(s: &str, flag: &str) -> Result<T, CliError> {
+    s.parse()
+        .map_err(|_| CliError(format!("invalid value '{}' for {}", s, flag)))
+}
@@ -0,0 +1,172 @@
+use std::path::{Path, PathBuf};
+
+use aoc_common::input::{self, StreamFn};
+use aoc_common::{DynSolution, Part};
+
+use crate::cli::CliError;
+
+/// A registered day: its directory in the repository and its solution.
+pub struct Day {
+    pub number: u8,
+    pub dir: &'static str,
+    pub solution: &'static dyn DynSolution,
+    /// Bounded-memory solver for `run --stream`, for days that can answer in one pass.
+    pub stream: Option<StreamFn>,
+}
+
+impl Day {
+    /// The puzzle title, i.e. the directory name without its `NN. ` prefix.
+    pub fn title(&self) -> &'static str {
+        self.dir
+            .split_once(". ")
+            .map_or(self.dir, |(_, title)| title)
+    }
+
+    /// The committed puzzle input, `<root>/<dir>/input.txt`.
+    pub fn input_path(&self, root: &Path) -> PathBuf {
+        root.join(self.dir).join("input.txt")
+    }
+
+    /// The committed answer, `<root>/<dir>/output_part_one.txt` (or `_two`).
+    pub fn output_path(&self, root: &Path, part: Part) -> PathBuf {
+        let name = match part {
+            Part::One => "output_part_one.txt",
+            Part::Two => "output_part_two.txt",
+        };
+        root.join(self.dir).join(name)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        dir: "01. Secret Entrance",
+        solution: &day01::Day01,
+        stream: Some(day01::stream_bytes),
+    },
+    Day {
+        number: 2,
+        dir: "02. Gift Shop",
+        solution: &day02::Day02,
+        stream: None,
+    },
+    Day {
+        number: 3,
+        dir: "03. Lobby",
+        solution: &day03::Day03,
+        stream: Some(input::stream_answers::<day03::Day03>),
+    },
+    Day {
+        number: 4,
+        dir: "04. Printing Department",
+        solution: &day04::Day04,
+        stream: None,
+    },
+    Day {
+        number: 5,
+        dir: "05. Cafeteria",
+        solution: &day05::Day05,
+        stream: Some(input::stream_answers::<day05::Day05>),
+    },
+    Day {
+        number: 6,
+        dir: "06. Trash Compactor",
+        solution: &day06::Day06,
+        stream: None,
+    },
+    Day {
+        number: 7,
+        dir: "07. Laboratories",
+        solution: &day07::Day07,
+        stream: None,
+    },
+    Day {
+        number: 8,
+        dir: "08. Playground",
+        solution: &day08::Day08,
+        stream: None,
+    },
+    Day {
+        number: 9,
+        dir: "09. Movie Theater",
+        solution: &day09::Day09,
+        stream: None,
+    },
+    Day {
+        number: 10,
+        dir: "10. Factory",
+        solution: &day10::Day10,
+        stream: None,
+    },
+    Day {
+        number: 11,
+        dir: "11. Reactor",
+        solution: &day11::Day11,
+        stream: None,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.number == number)
+}
+
+/// The requested day, or every registered day if none was given.
+pub fn select(day: Option<u8>) -> Result<Vec<&'static Day>, CliError> {
+    match day {
+        Some(n) => find(n)
+            .map(|d| vec![d])
+            .ok_or_else(|| CliError(format!("day {} is not registered", n))),
+        None => Ok(DAYS.iter().collect()),
+    }
+}
+
+/// The repository root, i.e. the directory holding the `NN. Title` day directories.
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate lives inside the repository")
+        .to_path_buf()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use aoc_common::{Precision, examples};
+
+    use super::*;
+
+    /// Every day's `topic.txt` must yield at least one example, and each answer it gives must
+    /// match the solver's, without any fixtures of the day's own.
+    #[test]
+    fn topic_examples() {
+        // The description's part one example makes 10 connections; the solver always makes 1000.
+        let unchecked = [(8, Part::One)];
+        let root = repo_root();
+        for day in DAYS {
+            let topic = fs::read_to_string(root.join(day.dir).join("topic.txt")).unwrap();
+            let found = examples::from_topic(&topic);
+            let mut checked = 0;
+            for (part, example) in Part::BOTH.into_iter().zip([found.part_one, found.part_two]) {
+                let Some(example) = example else { continue };
+                if unchecked.contains(&(day.number, part)) {
+                    continue;
+                }
+                let parsed = day.solution.parse(&example.input).unwrap();
+                assert_eq!(
+                    parsed.solve(part, Precision::Fixed).unwrap(),
+                    example.answer,
+                    "day {} part {} example",
+                    day.number,
+                    part
+                );
+                checked += 1;
+            }
+            assert!(
+                checked > 0,
+                "day {} topic.txt yields no examples",
+                day.number
+            );
+        }
+    }
+}
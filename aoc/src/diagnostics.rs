@@ -0,0 +1,36 @@
+use std::path::Path;
+
+use aoc_common::ParseError;
+
+/// Renders a parse error with the offending line and a caret under the bad text:
+///
+/// ```text
+/// day 5: invalid number '12x'
+///   --> 05. Cafeteria/input.txt:3:4
+///    |
+///  3 | 10-12x
+///    |    ^^^
+/// ```
+pub fn render(err: &ParseError, path: &Path) -> String {
+    let line_no = err.line.to_string();
+    let gutter = " ".repeat(line_no.len());
+    let text = err.line_text.replace('\t', " ");
+    let padding = " ".repeat(err.column - 1);
+    let carets = "^".repeat(err.len);
+
+    format!(
+        "day {}: {}\n{} --> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}",
+        err.day,
+        err.message,
+        gutter,
+        path.display(),
+        err.line,
+        err.column,
+        gutter,
+        line_no,
+        text,
+        gutter,
+        padding,
+        carets
+    )
+}
@@ -0,0 +1,313 @@
+mod alloc;
+mod bench;
+mod cache;
+mod cli;
+mod days;
+mod diagnostics;
+mod json;
+mod markdown;
+mod pool;
+mod ranges;
+mod report;
+mod scaffold;
+mod sha256;
+mod verify;
+
+use std::error::Error;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use aoc_common::{AnswerError, InputError, Part, Precision, trace};
+use cache::Cache;
+use cli::{CliError, Command, RunArgs};
+use days::Day;
+
+#[global_allocator]
+static GLOBAL: alloc::CountingAlloc = alloc::CountingAlloc;
+
+fn main() -> ExitCode {
+    let command = match cli::parse(std::env::args().skip(1)) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, cli::USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match command {
+        Command::Help => {
+            println!("{}", cli::USAGE);
+            Ok(ExitCode::SUCCESS)
+        }
+        Command::Run(args) if args.day.is_none() => run_all(&args),
+        Command::Run(args) => run(&args).map(|()| ExitCode::SUCCESS),
+        Command::Verify(args) => verify::verify(&args),
+        Command::Report(args) => report::report(&args),
+        Command::Bench(args) => bench::bench(&args),
+        Command::Ranges(args) => ranges::ranges(&args).map(|()| ExitCode::SUCCESS),
+        Command::New(args) => scaffold::new_day(&args).map(|()| ExitCode::SUCCESS),
+    };
+
+    match result {
+        Ok(code) => code,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the requested parts of one day and writes one answer per line.
+fn run(args: &RunArgs) -> Result<(), Box<dyn Error>> {
+    let number = args.day.expect("--day is given without --all");
+    let day =
+        days::find(number).ok_or_else(|| CliError(format!("day {} is not registered", number)))?;
+    let root = days::repo_root();
+    let stdin = args
+        .input
+        .as_ref()
+        .is_some_and(|path| path.as_os_str() == "-");
+    let input_path = match &args.input {
+        _ if stdin => PathBuf::from("<stdin>"),
+        Some(path) => path.clone(),
+        None => day.input_path(&root),
+    };
+    let input_error = |e: InputError| match e {
+        InputError::Io(e) => format!("cannot read {}: {}", input_path.display(), e),
+        InputError::Parse(e) => diagnostics::render(&e, &input_path),
+    };
+    if let Some(path) = &args.trace {
+        let file =
+            File::create(path).map_err(|e| format!("cannot create {}: {}", path.display(), e))?;
+        trace::install(Box::new(BufWriter::new(file)));
+    }
+
+    let answers: Vec<(Part, Result<String, AnswerError>)> = if stdin || args.stream {
+        // Read as a stream: neither hashed nor cached, since that would mean holding it all.
+        let mut reader: Box<dyn BufRead> = if stdin {
+            Box::new(io::stdin().lock())
+        } else {
+            let file = File::open(&input_path)
+                .map_err(|e| format!("cannot read {}: {}", input_path.display(), e))?;
+            Box::new(BufReader::with_capacity(1 << 20, file))
+        };
+        if args.stream {
+            let stream = day.stream.ok_or_else(|| {
+                CliError(format!("day {} cannot be solved with --stream", number))
+            })?;
+            let [one, two] = stream(&mut reader).map_err(input_error)?;
+            parts(args)
+                .into_iter()
+                .map(|part| match part {
+                    Part::One => (part, one.clone()),
+                    Part::Two => (part, two.clone()),
+                })
+                .collect()
+        } else {
+            let parsed = day
+                .solution
+                .parse_reader(&mut reader)
+                .map_err(input_error)?;
+            let precision = precision(args);
+            parts(args)
+                .into_iter()
+                .map(|part| {
+                    aoc_common::trace!(day.number, "part", part = part.number());
+                    (part, parsed.solve(part, precision))
+                })
+                .collect()
+        }
+    } else {
+        let bytes = fs::read(&input_path)
+            .map_err(|e| format!("cannot read {}: {}", input_path.display(), e))?;
+        if args.input.is_none()
+            && !args.save
+            && let Some(warning) = cache::stale_warning(day, &root, &bytes)
+        {
+            eprintln!("{}", warning);
+        }
+        // A cached answer would skip the steps being traced.
+        let cache = (!args.no_cache && args.trace.is_none()).then(|| Cache::new(&root));
+        let answers = cache::solve(day, &bytes, &parts(args), precision(args), cache.as_ref())
+            .map_err(input_error)?;
+        if args.save {
+            save(day, &root, &bytes, &answers)?;
+        }
+        answers.into_iter().map(|a| (a.part, a.answer)).collect()
+    };
+    if let Some(path) = &args.trace {
+        trace::finish().map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+    }
+    let answers = answers
+        .into_iter()
+        .map(|(part, answer)| {
+            answer.map_err(|e| format!("day {} part {}: {}", day.number, part, e))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    };
+    for answer in answers {
+        writeln!(out, "{}", answer)?;
+    }
+    Ok(())
+}
+
+/// Writes the answers to the day's `output_part_*.txt` and records `input` as the input they
+/// were produced from.
+fn save(day: &Day, root: &Path, input: &[u8], answers: &[cache::Answer]) -> Result<(), String> {
+    for a in answers {
+        let answer = a
+            .answer
+            .as_ref()
+            .map_err(|e| format!("day {} part {}: {}", day.number, a.part, e))?;
+        let path = day.output_path(root, a.part);
+        fs::write(&path, format!("{}\n", answer))
+            .map_err(|e| format!("cannot write {}: {}", path.display(), e))?;
+    }
+    cache::record_fingerprint(day, root, input).map_err(|e| e.to_string())
+}
+
+/// One day and part answered by a `run --all` task.
+struct Solved {
+    answer: String,
+    time: Duration,
+    cached: bool,
+}
+
+/// Runs every registered day on a pool of `--jobs` threads. Each day and part is its own task
+/// that reads and parses its input, so a slow or failing day does not hold up the others; the
+/// table is still written in day order.
+fn run_all(args: &RunArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let root = days::repo_root();
+    let precision = precision(args);
+
+    let cache = (!args.no_cache).then(|| Cache::new(&root));
+
+    let mut labels = Vec::new();
+    let mut tasks: Vec<pool::Task<Result<Solved, String>>> = Vec::new();
+    for day in days::DAYS {
+        for part in parts(args) {
+            let input_path = day.input_path(&root);
+            let cache = cache.clone();
+            labels.push((day, part));
+            tasks.push(Box::new(move || {
+                let bytes = fs::read(&input_path)
+                    .map_err(|e| format!("cannot read {}: {}", input_path.display(), e))?;
+                let start = Instant::now();
+                let answer = cache::solve(day, &bytes, &[part], precision, cache.as_ref())
+                    .map_err(|e| match e {
+                        InputError::Io(e) => e.to_string(),
+                        InputError::Parse(e) => diagnostics::render(&e, &input_path),
+                    })?
+                    .remove(0);
+                let text = answer.answer.map_err(|e| e.to_string())?;
+                Ok(Solved {
+                    answer: text,
+                    time: start.elapsed(),
+                    cached: answer.cached,
+                })
+            }));
+        }
+    }
+    for day in days::DAYS {
+        if let Ok(bytes) = fs::read(day.input_path(&root))
+            && let Some(warning) = cache::stale_warning(day, &root, &bytes)
+        {
+            eprintln!("{}", warning);
+        }
+    }
+
+    let outcomes = pool::run(tasks, args.jobs, Some(args.timeout));
+
+    let mut rows = Vec::new();
+    let mut errors = Vec::new();
+    for (&(day, part), outcome) in labels.iter().zip(outcomes) {
+        let (answer, time, status) = match outcome {
+            pool::Outcome::Done(Ok(Solved {
+                answer,
+                cached: true,
+                ..
+            })) => (answer, "-".to_string(), "cached".to_string()),
+            pool::Outcome::Done(Ok(Solved { answer, time, .. })) => {
+                (answer, format!("{:.3?}", time), "ok".to_string())
+            }
+            pool::Outcome::Done(Err(e)) => {
+                errors.push(format!("day {} part {}: {}", day.number, part, e));
+                ("-".to_string(), "-".to_string(), "error".to_string())
+            }
+            pool::Outcome::TimedOut => {
+                errors.push(format!(
+                    "day {} part {}: no answer after {:?}",
+                    day.number, part, args.timeout
+                ));
+                ("-".to_string(), "-".to_string(), "timeout".to_string())
+            }
+            pool::Outcome::Panicked(message) => {
+                errors.push(format!(
+                    "day {} part {}: panicked: {}",
+                    day.number, part, message
+                ));
+                ("-".to_string(), "-".to_string(), "panic".to_string())
+            }
+        };
+        rows.push([
+            day.number.to_string(),
+            day.title().to_string(),
+            part.to_string(),
+            answer,
+            time,
+            status.to_uppercase(),
+        ]);
+    }
+
+    let header = ["Day", "Title", "Part", "Answer", "Time", "Status"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(File::create(path)?),
+        None => Box::new(io::stdout().lock()),
+    };
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, w)| format!("{:<w$}", cell, w = w))
+            .collect();
+        writeln!(out, "{}", line.join("  ").trim_end())?;
+    }
+    for e in &errors {
+        eprintln!("error: {}", e);
+    }
+
+    Ok(if errors.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+fn precision(args: &RunArgs) -> Precision {
+    if args.exact {
+        Precision::Exact
+    } else {
+        Precision::Fixed
+    }
+}
+
+fn parts(args: &RunArgs) -> Vec<Part> {
+    match args.part {
+        Some(part) => vec![part],
+        None => Part::BOTH.to_vec(),
+    }
+}
@@ -0,0 +1,463 @@
+//! A minimal Markdown to HTML renderer, enough for the days' `solution.md` write-ups.
+//!
+//! Supported: ATX headings, paragraphs, fenced code blocks, nested bullet and numbered lists,
+//! block quotes, horizontal rules and pipe tables, and inline code, `**strong**`, `*emphasis*`,
+//! links and images. Everything else is rendered as escaped text.
+
+/// Renders a Markdown document as an HTML fragment.
+pub fn to_html(markdown: &str) -> String {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut out = String::new();
+    blocks(&lines, &mut out);
+    out
+}
+
+/// `text` with the characters that are special in HTML escaped.
+pub fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn blocks(lines: &[&str], out: &mut String) {
+    let mut i = 0;
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim_start();
+
+        if trimmed.is_empty() {
+            i += 1;
+        } else if let Some(fence) = fence(trimmed) {
+            let info = trimmed[fence.len()..].trim();
+            i += 1;
+            let start = i;
+            while i < lines.len() && !lines[i].trim_start().starts_with(fence) {
+                i += 1;
+            }
+            let code: Vec<&str> = lines[start..i].to_vec();
+            i += 1;
+            match info.split_whitespace().next() {
+                Some(lang) => {
+                    out.push_str(&format!("<pre><code class=\"language-{}\">", escape(lang)))
+                }
+                None => out.push_str("<pre><code>"),
+            }
+            for line in code {
+                out.push_str(&escape(line));
+                out.push('\n');
+            }
+            out.push_str("</code></pre>\n");
+        } else if let Some((level, text)) = heading(trimmed) {
+            out.push_str(&format!(
+                "<h{0} id=\"{1}\">{2}</h{0}>\n",
+                level,
+                slug(text),
+                inline(text)
+            ));
+            i += 1;
+        } else if is_rule(trimmed) {
+            out.push_str("<hr>\n");
+            i += 1;
+        } else if trimmed.starts_with('>') {
+            let mut quoted = Vec::new();
+            while i < lines.len() {
+                let Some(rest) = lines[i].trim_start().strip_prefix('>') else {
+                    break;
+                };
+                quoted.push(rest.strip_prefix(' ').unwrap_or(rest));
+                i += 1;
+            }
+            out.push_str("<blockquote>\n");
+            blocks(&quoted, out);
+            out.push_str("</blockquote>\n");
+        } else if is_table(lines, i) {
+            i = table(lines, i, out);
+        } else if marker(line).is_some() {
+            i = list(lines, i, out);
+        } else {
+            let start = i;
+            while i < lines.len() && !lines[i].trim().is_empty() && !starts_block(lines, i) {
+                i += 1;
+            }
+            let text: Vec<&str> = lines[start..i].iter().map(|l| l.trim()).collect();
+            out.push_str(&format!("<p>{}</p>\n", inline(&text.join("\n"))));
+        }
+    }
+}
+
+/// Whether line `i` begins something other than a paragraph, and so ends one.
+fn starts_block(lines: &[&str], i: usize) -> bool {
+    let trimmed = lines[i].trim_start();
+    fence(trimmed).is_some()
+        || heading(trimmed).is_some()
+        || is_rule(trimmed)
+        || trimmed.starts_with('>')
+        || is_table(lines, i)
+        || marker(lines[i]).is_some()
+}
+
+fn fence(trimmed: &str) -> Option<&'static str> {
+    ["```", "~~~"]
+        .into_iter()
+        .find(|fence| trimmed.starts_with(fence))
+}
+
+/// The level and text of an ATX heading such as `## Part 1`.
+fn heading(trimmed: &str) -> Option<(usize, &str)> {
+    let level = trimmed.bytes().take_while(|&b| b == b'#').count();
+    let rest = &trimmed[level..];
+    if !(1..=6).contains(&level) || !(rest.is_empty() || rest.starts_with(' ')) {
+        return None;
+    }
+    Some((level, rest.trim().trim_end_matches('#').trim_end()))
+}
+
+/// `---`, `***` or `___`, possibly spaced out.
+fn is_rule(trimmed: &str) -> bool {
+    let chars: Vec<char> = trimmed.chars().filter(|c| !c.is_whitespace()).collect();
+    chars.len() >= 3
+        && ['-', '*', '_']
+            .iter()
+            .any(|&m| chars.iter().all(|&c| c == m))
+}
+
+/// A list item marker: its indentation, whether it is numbered (with the number), and the
+/// byte offset at which the item's text starts.
+struct Marker {
+    indent: usize,
+    number: Option<u64>,
+    content: usize,
+}
+
+fn marker(line: &str) -> Option<Marker> {
+    let indent = line.len() - line.trim_start_matches(' ').len();
+    let rest = &line[indent..];
+    let (number, len) = if rest.starts_with(['-', '*', '+']) {
+        (None, 1)
+    } else {
+        let digits = rest.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 || digits > 9 || !rest[digits..].starts_with(['.', ')']) {
+            return None;
+        }
+        (rest[..digits].parse().ok(), digits + 1)
+    };
+    let after = &rest[len..];
+    if !after.starts_with(' ') && !after.is_empty() {
+        return None;
+    }
+    let spaces = after.len() - after.trim_start_matches(' ').len();
+    Some(Marker {
+        indent,
+        number,
+        content: indent + len + spaces.clamp(1, 4).min(after.len()),
+    })
+}
+
+fn indent(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Renders the list starting at line `i`, returning the line after it. Items are lines with a
+/// marker of the same kind at the same indentation; more deeply indented lines belong to the
+/// item above them.
+fn list(lines: &[&str], mut i: usize, out: &mut String) -> usize {
+    let first = marker(lines[i]).expect("a list starts with a marker");
+    match first.number {
+        None => out.push_str("<ul>\n"),
+        Some(1) => out.push_str("<ol>\n"),
+        Some(n) => out.push_str(&format!("<ol start=\"{}\">\n", n)),
+    }
+
+    while i < lines.len() {
+        let Some(item) = marker(lines[i]) else { break };
+        if item.indent != first.indent || item.number.is_some() != first.number.is_some() {
+            break;
+        }
+
+        let mut body = vec![&lines[i][item.content.min(lines[i].len())..]];
+        i += 1;
+        while i < lines.len() {
+            let line = lines[i];
+            if line.trim().is_empty() {
+                let next = (i..lines.len()).find(|&j| !lines[j].trim().is_empty());
+                match next {
+                    Some(j) if indent(lines[j]) > first.indent => {
+                        body.push("");
+                        i += 1;
+                    }
+                    _ => break,
+                }
+            } else if indent(line) > first.indent {
+                body.push(&line[indent(line).min(item.content)..]);
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        // The item's own text, up to the first nested block, is rendered without a paragraph.
+        let text_end = (1..body.len())
+            .find(|&j| body[j].trim().is_empty() || starts_block(&body, j))
+            .unwrap_or(body.len());
+        let text: Vec<&str> = body[..text_end].iter().map(|l| l.trim()).collect();
+        out.push_str("<li>");
+        out.push_str(&inline(&text.join("\n")));
+        if text_end < body.len() {
+            out.push('\n');
+            blocks(&body[text_end..], out);
+        }
+        out.push_str("</li>\n");
+
+        // A blank line between items keeps the list going.
+        if i < lines.len()
+            && lines[i].trim().is_empty()
+            && let Some(j) = (i..lines.len()).find(|&j| !lines[j].trim().is_empty())
+            && marker(lines[j]).is_some_and(|m| m.indent == first.indent)
+        {
+            i = j;
+        }
+    }
+
+    out.push_str(if first.number.is_some() {
+        "</ol>\n"
+    } else {
+        "</ul>\n"
+    });
+    i
+}
+
+fn cells(line: &str) -> Vec<&str> {
+    let line = line.trim();
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let line = line.strip_suffix('|').unwrap_or(line);
+    line.split('|').map(str::trim).collect()
+}
+
+/// Whether line `i` is a table header: a row of cells followed by a `| --- | :-: |` line.
+fn is_table(lines: &[&str], i: usize) -> bool {
+    lines[i].trim_start().starts_with('|')
+        && lines.get(i + 1).is_some_and(|next| {
+            next.contains('-')
+                && cells(next)
+                    .iter()
+                    .all(|c| !c.is_empty() && c.chars().all(|ch| matches!(ch, '-' | ':' | ' ')))
+        })
+}
+
+/// Renders the table whose header is line `i`, returning the line after it.
+fn table(lines: &[&str], mut i: usize, out: &mut String) -> usize {
+    let align: Vec<&str> = cells(lines[i + 1])
+        .iter()
+        .map(|c| match (c.starts_with(':'), c.ends_with(':')) {
+            (true, true) => " style=\"text-align:center\"",
+            (true, false) => " style=\"text-align:left\"",
+            (false, true) => " style=\"text-align:right\"",
+            _ => "",
+        })
+        .collect();
+    let row = |line: &str, tag: &str, out: &mut String| {
+        out.push_str("<tr>");
+        for (j, cell) in cells(line).iter().enumerate() {
+            let style = align.get(j).copied().unwrap_or("");
+            out.push_str(&format!("<{0}{1}>{2}</{0}>", tag, style, inline(cell)));
+        }
+        out.push_str("</tr>\n");
+    };
+
+    out.push_str("<table>\n<thead>\n");
+    row(lines[i], "th", out);
+    out.push_str("</thead>\n<tbody>\n");
+    i += 2;
+    while i < lines.len() && lines[i].trim_start().starts_with('|') {
+        row(lines[i], "td", out);
+        i += 1;
+    }
+    out.push_str("</tbody>\n</table>\n");
+    i
+}
+
+/// An anchor for a heading: its letters and digits in lower case, words joined by `-`.
+fn slug(text: &str) -> String {
+    let mut slug = String::new();
+    for c in text.chars() {
+        if c.is_alphanumeric() {
+            slug.extend(c.to_lowercase());
+        } else if (c == ' ' || c == '-') && !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    slug.trim_end_matches('-').to_string()
+}
+
+/// Renders the inline markup of one block's text.
+fn inline(text: &str) -> String {
+    let mut out = String::new();
+    let mut i = 0;
+    while i < text.len() {
+        let rest = &text[i..];
+        let c = rest.chars().next().unwrap();
+
+        if c == '\\'
+            && let Some(next) = rest[1..].chars().next()
+            && next.is_ascii_punctuation()
+        {
+            out.push_str(&escape(&next.to_string()));
+            i += 2;
+            continue;
+        }
+        if c == '`' {
+            let ticks = rest.bytes().take_while(|&b| b == b'`').count();
+            let delimiter = &rest[..ticks];
+            if let Some(end) = rest[ticks..].find(delimiter) {
+                let code = rest[ticks..ticks + end].trim();
+                out.push_str(&format!("<code>{}</code>", escape(code)));
+                i += 2 * ticks + end;
+                continue;
+            }
+        }
+        if let Some(delimiter) = ["**", "__"].into_iter().find(|d| rest.starts_with(d))
+            && let Some(end) = closing(text, i, delimiter)
+        {
+            let inner = &text[i + 2..end];
+            out.push_str(&format!("<strong>{}</strong>", inline(inner)));
+            i = end + 2;
+            continue;
+        }
+        if (c == '*' || c == '_')
+            && let Some(end) = closing(text, i, &text[i..i + 1])
+        {
+            let inner = &text[i + 1..end];
+            out.push_str(&format!("<em>{}</em>", inline(inner)));
+            i = end + 1;
+            continue;
+        }
+        if let Some((image, label, url, len)) = link(rest) {
+            if image {
+                out.push_str(&format!(
+                    "<img src=\"{}\" alt=\"{}\">",
+                    escape(url),
+                    escape(label)
+                ));
+            } else {
+                out.push_str(&format!(
+                    "<a href=\"{}\">{}</a>",
+                    escape(url),
+                    inline(label)
+                ));
+            }
+            i += len;
+            continue;
+        }
+
+        out.push_str(&escape(&c.to_string()));
+        i += c.len_utf8();
+    }
+    out
+}
+
+/// The byte offset of the delimiter closing the emphasis that `delimiter` opens at `start`.
+/// Like CommonMark, an opener must be followed by text and a closer preceded by it, and
+/// underscores inside words (`max_subseq_k`) never count.
+fn closing(text: &str, start: usize, delimiter: &str) -> Option<usize> {
+    let word = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+    let underscore = delimiter.starts_with('_');
+    let before = text[..start].chars().next_back();
+    let after = text[start + delimiter.len()..].chars().next();
+    if after.is_none_or(char::is_whitespace) || (underscore && word(before)) {
+        return None;
+    }
+
+    let mut from = start + delimiter.len() + 1;
+    while let Some(offset) = text.get(from..)?.find(delimiter) {
+        let end = from + offset;
+        let before = text[..end].chars().next_back();
+        let after = text[end + delimiter.len()..].chars().next();
+        // A single `*` must not be half of a `**`.
+        let doubled = delimiter.len() == 1 && text[end..].starts_with(&delimiter.repeat(2));
+        let flanked = before.is_some_and(|c| !c.is_whitespace());
+        if flanked && !(underscore && word(after)) && !doubled {
+            return Some(end);
+        }
+        from = end + delimiter.len();
+    }
+    None
+}
+
+/// A `[label](url)` link or `![alt](url)` image at the start of `text`: whether it is an
+/// image, its label, its URL and its length in bytes.
+fn link(text: &str) -> Option<(bool, &str, &str, usize)> {
+    let image = text.starts_with("![");
+    let open = if image { 2 } else { 1 };
+    if !image && !text.starts_with('[') {
+        return None;
+    }
+    let label_end = open + text[open..].find("](")?;
+    let url_start = label_end + 2;
+    let url_end = url_start + text[url_start..].find(')')?;
+    Some((
+        image,
+        &text[open..label_end],
+        text[url_start..url_end].trim(),
+        url_end + 1,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_blocks() {
+        let md = "# Day 5: Cafeteria\n\nSome *text* and `a < b`\nover two lines.\n\n\
+                  ```rust\nfn main() {}\n```\n\n> quoted\n\n---\n";
+        assert_eq!(
+            to_html(md),
+            "<h1 id=\"day-5-cafeteria\">Day 5: Cafeteria</h1>\n\
+             <p>Some <em>text</em> and <code>a &lt; b</code>\nover two lines.</p>\n\
+             <pre><code class=\"language-rust\">fn main() {}\n</code></pre>\n\
+             <blockquote>\n<p>quoted</p>\n</blockquote>\n<hr>\n"
+        );
+    }
+
+    #[test]
+    fn renders_nested_lists() {
+        let md =
+            "**Steps:**\n1. Parse\n   - ranges\n     - sorted\n2. Merge\n\n3. Count\n- other\n";
+        assert_eq!(
+            to_html(md),
+            "<p><strong>Steps:</strong></p>\n<ol>\n<li>Parse\n<ul>\n<li>ranges\n<ul>\n\
+             <li>sorted</li>\n</ul>\n</li>\n</ul>\n</li>\n<li>Merge</li>\n<li>Count</li>\n\
+             </ol>\n<ul>\n<li>other</li>\n</ul>\n"
+        );
+    }
+
+    #[test]
+    fn renders_tables() {
+        let md = "| Aspect | Part 1 |\n| :----- | -----: |\n| **Domain** | GF(2) |\n";
+        assert_eq!(
+            to_html(md),
+            "<table>\n<thead>\n<tr><th style=\"text-align:left\">Aspect</th>\
+             <th style=\"text-align:right\">Part 1</th></tr>\n</thead>\n<tbody>\n\
+             <tr><td style=\"text-align:left\"><strong>Domain</strong></td>\
+             <td style=\"text-align:right\">GF(2)</td></tr>\n</tbody>\n</table>\n"
+        );
+    }
+
+    #[test]
+    fn leaves_intraword_underscores_and_stray_markers_alone() {
+        assert_eq!(
+            inline("max_subseq_k, 2 * 3 * 4, _them_ and [site](https://a.b/?x=1&y=2)"),
+            "max_subseq_k, 2 * 3 * 4, <em>them</em> and \
+             <a href=\"https://a.b/?x=1&amp;y=2\">site</a>"
+        );
+        assert_eq!(inline("\\*not em\\*"), "*not em*");
+    }
+}
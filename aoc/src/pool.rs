@@ -0,0 +1,131 @@
+//! A small thread pool for running independent tasks with a per-task time limit.
+//!
+//! Each task runs on its own thread and at most `jobs` run at once. std threads cannot be
+//! cancelled, so a task that exceeds its time limit is reported as timed out and its thread is
+//! left to finish (or die with the process) while its slot goes to the next task.
+
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub type Task<T> = Box<dyn FnOnce() -> T + Send>;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome<T> {
+    Done(T),
+    TimedOut,
+    /// The task panicked; holds the panic message.
+    Panicked(String),
+}
+
+/// Runs `tasks` on up to `jobs` threads and returns their outcomes in task order.
+pub fn run<T: Send + 'static>(
+    tasks: Vec<Task<T>>,
+    jobs: usize,
+    timeout: Option<Duration>,
+) -> Vec<Outcome<T>> {
+    let jobs = jobs.max(1);
+    let (sender, receiver) = mpsc::channel();
+    let mut outcomes: Vec<Option<Outcome<T>>> = tasks.iter().map(|_| None).collect();
+    let mut pending = tasks.into_iter().enumerate();
+    // Running tasks and their deadlines.
+    let mut running: HashMap<usize, Option<Instant>> = HashMap::new();
+
+    loop {
+        while running.len() < jobs {
+            let Some((index, task)) = pending.next() else {
+                break;
+            };
+            let sender = sender.clone();
+            thread::spawn(move || {
+                let result = panic::catch_unwind(AssertUnwindSafe(task));
+                // The receiver is gone once every task has an outcome.
+                let _ = sender.send((index, result));
+            });
+            running.insert(index, timeout.map(|t| Instant::now() + t));
+        }
+        if running.is_empty() {
+            break;
+        }
+
+        let next_deadline = running.values().flatten().min().copied();
+        let message = match next_deadline {
+            Some(deadline) => {
+                match receiver.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(message) => Some(message),
+                    Err(mpsc::RecvTimeoutError::Timeout) => None,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => unreachable!("sender is held"),
+                }
+            }
+            None => Some(receiver.recv().expect("sender is held")),
+        };
+
+        if let Some((index, result)) = message {
+            // A late result from a task that already timed out is dropped.
+            if running.remove(&index).is_some() {
+                outcomes[index] = Some(match result {
+                    Ok(value) => Outcome::Done(value),
+                    Err(payload) => Outcome::Panicked(panic_message(payload.as_ref())),
+                });
+            }
+        }
+
+        let now = Instant::now();
+        running.retain(|&index, deadline| {
+            let expired = deadline.is_some_and(|d| d <= now);
+            if expired {
+                outcomes[index] = Some(Outcome::TimedOut);
+            }
+            !expired
+        });
+    }
+
+    outcomes
+        .into_iter()
+        .map(|o| o.expect("every task has an outcome"))
+        .collect()
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "task panicked".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_task_order_and_isolates_failures() {
+        let tasks: Vec<Task<u32>> = vec![
+            Box::new(|| {
+                thread::sleep(Duration::from_millis(50));
+                1
+            }),
+            Box::new(|| panic!("boom")),
+            Box::new(|| {
+                thread::sleep(Duration::from_secs(10));
+                3
+            }),
+            Box::new(|| 4),
+        ];
+
+        let outcomes = run(tasks, 2, Some(Duration::from_millis(500)));
+        assert_eq!(
+            outcomes,
+            vec![
+                Outcome::Done(1),
+                Outcome::Panicked("boom".to_string()),
+                Outcome::TimedOut,
+                Outcome::Done(4),
+            ]
+        );
+    }
+}
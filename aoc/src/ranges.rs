@@ -0,0 +1,112 @@
+//! `aoc ranges`: breaks the day 2 answers down by input range, largest part two sum first, so
+//! that the ranges holding most of the invalid IDs stand out.
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+use aoc_common::{BigUint, Solution};
+use day02::{Day02, Radix, RangeTally};
+
+use crate::cli::{CliError, RangesArgs};
+use crate::days;
+use crate::diagnostics;
+
+pub fn ranges(args: &RangesArgs) -> Result<(), Box<dyn Error>> {
+    let day = days::find(Day02::DAY).expect("day 2 is registered");
+    let (path, text) = match &args.input {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut text = String::new();
+            io::stdin().read_to_string(&mut text)?;
+            (PathBuf::from("<stdin>"), text)
+        }
+        path => {
+            let path = path
+                .clone()
+                .unwrap_or_else(|| day.input_path(&days::repo_root()));
+            let text = fs::read_to_string(&path)
+                .map_err(|e| format!("cannot read {}: {}", path.display(), e))?;
+            (path, text)
+        }
+    };
+    let ranges = Day02::parse(&text).map_err(|e| CliError(diagnostics::render(&e, &path)))?;
+    let line = text.lines().next().unwrap_or("");
+    for diagnostic in &ranges.diagnostics {
+        let error = diagnostic.to_parse_error(line);
+        eprintln!("warning: {}", diagnostics::render(&error, &path));
+    }
+    let radix = Radix::new(args.radix);
+
+    let exact = "arbitrary precision does not overflow";
+    let mut tallies = day02::tally_ranges::<BigUint>(&ranges.listed, radix).expect(exact);
+    let total = day02::repeated_at_least_twice::<BigUint>(&ranges.merged, radix).expect(exact);
+    tallies.sort_by(|a, b| {
+        b.at_least_twice
+            .sum
+            .cmp(&a.at_least_twice.sum)
+            .then_with(|| a.range.cmp(&b.range))
+    });
+
+    let header = ["Rank", "Range", "Twice", "Sum", "Repeated", "Sum", "Share"];
+    let rows: Vec<[String; 7]> = tallies
+        .iter()
+        .take(args.top)
+        .enumerate()
+        .map(|(i, t)| row(i + 1, t, &total))
+        .collect();
+
+    let mut widths = header.map(str::len);
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+    for row in std::iter::once(&header.map(String::from)).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(i, (cell, w))| {
+                // Numbers are right-aligned, the range left-aligned.
+                if i == 1 {
+                    format!("{:<w$}", cell, w = w)
+                } else {
+                    format!("{:>w$}", cell, w = w)
+                }
+            })
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+    println!(
+        "{} of {} ranges; part two sums to {} over distinct IDs",
+        rows.len(),
+        tallies.len(),
+        total
+    );
+    Ok(())
+}
+
+/// One table row: counts and sums under both parts' rules, and the range's share of the part
+/// two answer (more than 100% in total when ranges overlap).
+fn row(rank: usize, tally: &RangeTally<BigUint>, total: &BigUint) -> [String; 7] {
+    let (a, b) = tally.range;
+    [
+        rank.to_string(),
+        format!("{}-{}", a, b),
+        tally.twice.count.to_string(),
+        tally.twice.sum.to_string(),
+        tally.at_least_twice.count.to_string(),
+        tally.at_least_twice.sum.to_string(),
+        share(&tally.at_least_twice.sum, total),
+    ]
+}
+
+fn share(part: &BigUint, total: &BigUint) -> String {
+    if total.is_zero() {
+        return "-".to_string();
+    }
+    // Only for display, so the precision of f64 is plenty.
+    let as_f64 = |n: &BigUint| n.to_string().parse::<f64>().unwrap_or(f64::INFINITY);
+    format!("{:.1}%", as_f64(part) / as_f64(total) * 100.0)
+}
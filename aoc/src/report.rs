@@ -0,0 +1,384 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime};
+
+use aoc_common::{Part, Precision};
+
+use crate::bench::format_duration;
+use crate::cli::{ReportArgs, ReportFormat};
+use crate::days::{self, Day};
+use crate::json::Value;
+use crate::markdown::{self, escape};
+use crate::sha256;
+use crate::verify::{self, Status};
+
+/// One row of the report: a part of a day as computed on this machine.
+struct Record {
+    day: &'static Day,
+    part: Part,
+    answer: Option<String>,
+    expected: Option<String>,
+    /// Shared by both parts of a day, which are solved from one parse.
+    parse_time: Option<Duration>,
+    solve_time: Option<Duration>,
+    input_sha256: Option<String>,
+    status: Status,
+}
+
+/// Solves every selected day and part and writes the results as JSON or CSV, to stdout or
+/// `--output`, or as an HTML site into the `--output` directory. Failures are recorded in the
+/// report rather than aborting it.
+pub fn report(args: &ReportArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let precision = if args.exact {
+        Precision::Exact
+    } else {
+        Precision::Fixed
+    };
+
+    let mut records = Vec::new();
+    for day in days::select(args.day)? {
+        records.extend(solve_day(day, precision)?);
+    }
+
+    let text = match args.format {
+        ReportFormat::Json => to_json(&records, precision).to_pretty() + "\n",
+        ReportFormat::Csv => to_csv(&records),
+        ReportFormat::Html => {
+            let dir = args
+                .output
+                .as_ref()
+                .expect("--format html requires --output");
+            write_site(dir, &records, precision)?;
+            println!("wrote {}", dir.join("index.html").display());
+            return Ok(ExitCode::SUCCESS);
+        }
+    };
+    match &args.output {
+        Some(path) => fs::write(path, text)?,
+        None => io::stdout().lock().write_all(text.as_bytes())?,
+    }
+    Ok(ExitCode::SUCCESS)
+}
+
+fn solve_day(day: &'static Day, precision: Precision) -> io::Result<Vec<Record>> {
+    let root = days::repo_root();
+    let input_path = day.input_path(&root);
+
+    let (input_sha256, parse_time, parsed) = match fs::read_to_string(&input_path) {
+        Err(e) => (
+            None,
+            None,
+            Err(format!("cannot read {}: {}", input_path.display(), e)),
+        ),
+        Ok(input) => {
+            let start = Instant::now();
+            let parsed = day.solution.parse(&input).map_err(|e| e.to_string());
+            (
+                Some(sha256::hex_digest(input.as_bytes())),
+                Some(start.elapsed()),
+                parsed,
+            )
+        }
+    };
+
+    Part::BOTH
+        .into_iter()
+        .map(|part| {
+            let expected = verify::read_expected(&day.output_path(&root, part))?;
+            let (answer, solve_time) = match &parsed {
+                Err(e) => (Err(e.clone()), None),
+                Ok(p) => {
+                    let start = Instant::now();
+                    let answer = p.solve(part, precision).map_err(|e| e.to_string());
+                    (answer, Some(start.elapsed()))
+                }
+            };
+            Ok(Record {
+                day,
+                part,
+                status: Status::of(&answer, &expected),
+                answer: answer.ok(),
+                expected,
+                parse_time,
+                solve_time,
+                input_sha256: input_sha256.clone(),
+            })
+        })
+        .collect()
+}
+
+fn error(status: &Status) -> Option<&str> {
+    match status {
+        Status::Error(e) => Some(e),
+        _ => None,
+    }
+}
+
+fn nanos(d: Option<Duration>) -> Option<u64> {
+    d.map(|d| d.as_nanos() as u64)
+}
+
+fn to_json(records: &[Record], precision: Precision) -> Value {
+    let generated_at = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let cpus = std::thread::available_parallelism().map_or(1, |n| n.get() as u64);
+
+    let results = records
+        .iter()
+        .map(|r| {
+            Value::Object(vec![
+                ("day".to_string(), u64::from(r.day.number).into()),
+                ("title".to_string(), r.day.title().into()),
+                ("part".to_string(), u64::from(r.part.number()).into()),
+                ("answer".to_string(), r.answer.clone().into()),
+                ("expected".to_string(), r.expected.clone().into()),
+                ("parse_ns".to_string(), nanos(r.parse_time).into()),
+                ("solve_ns".to_string(), nanos(r.solve_time).into()),
+                ("input_sha256".to_string(), r.input_sha256.clone().into()),
+                ("status".to_string(), r.status.name().into()),
+                ("error".to_string(), error(&r.status).into()),
+            ])
+        })
+        .collect();
+
+    Value::Object(vec![
+        ("generated_at".to_string(), generated_at.into()),
+        ("os".to_string(), std::env::consts::OS.into()),
+        ("arch".to_string(), std::env::consts::ARCH.into()),
+        ("cpus".to_string(), cpus.into()),
+        (
+            "precision".to_string(),
+            match precision {
+                Precision::Fixed => "fixed",
+                Precision::Exact => "exact",
+            }
+            .into(),
+        ),
+        ("results".to_string(), Value::Array(results)),
+    ])
+}
+
+fn to_csv(records: &[Record]) -> String {
+    let mut out = String::from(
+        "day,title,part,answer,expected,parse_ns,solve_ns,input_sha256,status,error\n",
+    );
+    for r in records {
+        let fields = [
+            r.day.number.to_string(),
+            r.day.title().to_string(),
+            r.part.to_string(),
+            r.answer.clone().unwrap_or_default(),
+            r.expected.clone().unwrap_or_default(),
+            nanos(r.parse_time).map_or(String::new(), |n| n.to_string()),
+            nanos(r.solve_time).map_or(String::new(), |n| n.to_string()),
+            r.input_sha256.clone().unwrap_or_default(),
+            r.status.name().to_string(),
+            error(&r.status).unwrap_or_default().to_string(),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    out
+}
+
+/// Quotes a field if it contains a separator, quote or line break (RFC 4180).
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+const STYLE: &str = "\
+body { font-family: system-ui, sans-serif; max-width: 52rem; margin: 2rem auto; padding: 0 1rem;
+       line-height: 1.5; color: #222; }
+table { border-collapse: collapse; margin: 1rem 0; }
+th, td { border: 1px solid #ccc; padding: 0.3rem 0.6rem; text-align: left; }
+td.answer, td.time { font-family: ui-monospace, monospace; }
+td.time { text-align: right; }
+code, pre { font-family: ui-monospace, monospace; background: #f4f4f4; }
+pre { padding: 0.6rem; overflow-x: auto; }
+.pass { color: #1a7f37; }
+.fail, .error { color: #cf222e; }
+.missing { color: #9a6700; }
+figure img { max-width: 100%; }
+";
+
+/// Writes `index.html`, a `dayNN.html` page per day and `style.css` into `dir`. Images found
+/// in a day's directory are copied to `dir/dayNN/` and shown on its page.
+fn write_site(dir: &Path, records: &[Record], precision: Precision) -> io::Result<()> {
+    let root = days::repo_root();
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join("style.css"), STYLE)?;
+
+    let mut rows = String::new();
+    for parts in records.chunk_by(|a, b| a.day.number == b.day.number) {
+        let day = parts[0].day;
+        let page = format!("day{:02}.html", day.number);
+        let images = copy_images(day, &root, dir)?;
+        fs::write(dir.join(&page), day_page(day, parts, &root, &images)?)?;
+
+        let link = |text: &str| format!("<a href=\"{}\">{}</a>", page, escape(text));
+        rows.push_str(&format!(
+            "<tr><td>{}</td><td>{}</td>",
+            link(&day.number.to_string()),
+            link(day.title())
+        ));
+        for r in parts {
+            rows.push_str(&answer_cell(r));
+        }
+        let solve: Option<Duration> = parts.iter().map(|r| r.solve_time).sum();
+        let status = parts
+            .iter()
+            .map(|r| &r.status)
+            .max_by_key(|s| severity(s))
+            .map_or("missing", Status::name);
+        rows.push_str(&format!(
+            "{}{}<td class=\"{2}\">{2}</td></tr>\n",
+            time_cell(parts[0].parse_time),
+            time_cell(solve),
+            status
+        ));
+    }
+
+    let body = format!(
+        "<h1>Advent of Code</h1>\n<table>\n<thead>\n<tr><th>Day</th><th>Title</th>\
+         <th>Part 1</th><th>Part 2</th><th>Parse</th><th>Solve</th><th>Status</th></tr>\n\
+         </thead>\n<tbody>\n{}</tbody>\n</table>\n<p>Solved on {} {} with {} precision.</p>\n",
+        rows,
+        std::env::consts::OS,
+        std::env::consts::ARCH,
+        match precision {
+            Precision::Fixed => "fixed",
+            Precision::Exact => "exact",
+        }
+    );
+    fs::write(dir.join("index.html"), html_page("Advent of Code", &body))
+}
+
+/// The page of one day: its rendered `solution.md`, its answers and its images.
+fn day_page(day: &Day, parts: &[Record], root: &Path, images: &[String]) -> io::Result<String> {
+    let title = format!("Day {}: {}", day.number, day.title());
+    let mut body = String::from("<nav><a href=\"index.html\">All days</a></nav>\n");
+
+    match fs::read_to_string(root.join(day.dir).join("solution.md")) {
+        Ok(text) => body.push_str(&markdown::to_html(&text)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            body.push_str(&format!("<h1>{}</h1>\n", escape(&title)));
+        }
+        Err(e) => return Err(e),
+    }
+
+    body.push_str(
+        "<h2 id=\"answers\">Answers</h2>\n<table>\n<thead>\n<tr><th>Part</th><th>Answer</th>\
+         <th>Expected</th><th>Solve</th><th>Status</th></tr>\n</thead>\n<tbody>\n",
+    );
+    for r in parts {
+        body.push_str(&format!(
+            "<tr><td>{}</td>{}<td class=\"answer\">{}</td>{}<td class=\"{4}\">{4}</td></tr>\n",
+            r.part,
+            answer_cell(r),
+            escape(r.expected.as_deref().unwrap_or("")),
+            time_cell(r.solve_time),
+            r.status.name()
+        ));
+    }
+    body.push_str("</tbody>\n</table>\n");
+    if let Some(parse) = parts[0].parse_time {
+        body.push_str(&format!(
+            "<p>Parsed in {}; input SHA-256 <code>{}</code>.</p>\n",
+            format_duration(parse),
+            parts[0].input_sha256.as_deref().unwrap_or("")
+        ));
+    }
+
+    if !images.is_empty() {
+        body.push_str("<h2 id=\"visualizations\">Visualizations</h2>\n");
+        for image in images {
+            let name = image.rsplit('/').next().unwrap_or(image);
+            body.push_str(&format!(
+                "<figure><img src=\"{}\" alt=\"{1}\"><figcaption>{1}</figcaption></figure>\n",
+                escape(image),
+                escape(name)
+            ));
+        }
+    }
+    Ok(html_page(&title, &body))
+}
+
+fn html_page(title: &str, body: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <title>{}</title>\n<link rel=\"stylesheet\" href=\"style.css\">\n</head>\n<body>\n{}\
+         </body>\n</html>\n",
+        escape(title),
+        body
+    )
+}
+
+/// A part's answer, or the error that prevented one.
+fn answer_cell(r: &Record) -> String {
+    let text = match (&r.answer, &r.status) {
+        (Some(answer), _) => answer.as_str(),
+        (None, Status::Error(e)) => e.as_str(),
+        (None, _) => "",
+    };
+    format!(
+        "<td class=\"answer {}\">{}</td>",
+        r.status.name(),
+        escape(text)
+    )
+}
+
+fn time_cell(d: Option<Duration>) -> String {
+    format!(
+        "<td class=\"time\">{}</td>",
+        d.map_or(String::new(), format_duration)
+    )
+}
+
+/// How bad a status is, for summarising a day's parts by the worst one.
+fn severity(status: &Status) -> u8 {
+    match status {
+        Status::Pass => 0,
+        Status::Missing => 1,
+        Status::Fail => 2,
+        Status::Error(_) => 3,
+    }
+}
+
+/// Copies the images in a day's directory to `<dir>/dayNN/`, returning their paths relative
+/// to `dir` in name order.
+fn copy_images(day: &Day, root: &Path, dir: &Path) -> io::Result<Vec<String>> {
+    let mut names: Vec<String> = fs::read_dir(root.join(day.dir))?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().into_owned())
+        .filter(|name| {
+            let ext = name.rsplit_once('.').map_or("", |(_, ext)| ext);
+            ["svg", "png", "gif", "jpg", "jpeg", "webp"]
+                .contains(&ext.to_ascii_lowercase().as_str())
+        })
+        .collect();
+    names.sort();
+
+    let folder = format!("day{:02}", day.number);
+    if !names.is_empty() {
+        fs::create_dir_all(dir.join(&folder))?;
+    }
+    names
+        .into_iter()
+        .map(|name| {
+            fs::copy(
+                root.join(day.dir).join(&name),
+                dir.join(&folder).join(&name),
+            )?;
+            Ok(format!("{}/{}", folder, name))
+        })
+        .collect()
+}
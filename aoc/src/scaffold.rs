@@ -0,0 +1,216 @@
+//! `aoc new`: creates the `NN. Title` directory of a new day from templates and registers its
+//! crate in the workspace, the runner's dependencies and [`crate::days::DAYS`].
+
+use std::error::Error;
+use std::fs;
+use std::path::Path;
+
+use crate::cli::{CliError, NewArgs};
+use crate::days;
+
+pub fn new_day(args: &NewArgs) -> Result<(), Box<dyn Error>> {
+    let dir = scaffold(&days::repo_root(), args.day, &args.title)?;
+    println!("created '{}' and registered day{:02}", dir, args.day);
+    println!("paste the puzzle into topic.txt and your input into input.txt, then rebuild");
+    Ok(())
+}
+
+/// Creates and registers day `day` under `root`, returning the new directory's name. Nothing
+/// is written if the day already exists or is already registered.
+fn scaffold(root: &Path, day: u8, title: &str) -> Result<String, Box<dyn Error>> {
+    if !(1..=25).contains(&day) {
+        return Err(CliError(format!("day {} is not between 1 and 25", day)).into());
+    }
+    if title.trim() != title || title.is_empty() || title.contains(['/', '\\', '"']) {
+        let msg = format!("invalid title '{}'", title);
+        return Err(CliError(msg).into());
+    }
+
+    let prefix = format!("{:02}. ", day);
+    for entry in fs::read_dir(root)? {
+        let name = entry?.file_name();
+        let name = name.to_string_lossy();
+        if name.starts_with(&prefix) {
+            let msg = format!(
+                "day {} already exists in '{}'; not overwriting it",
+                day, name
+            );
+            return Err(CliError(msg).into());
+        }
+    }
+
+    let dir = format!("{:02}. {}", day, title);
+    let krate = format!("day{:02}", day);
+    let name = format!("Day{:02}", day);
+
+    // Edit the registrations in memory first so a failure leaves the tree untouched.
+    let workspace_path = root.join("Cargo.toml");
+    let runner_path = root.join("aoc/Cargo.toml");
+    let days_path = root.join("aoc/src/days.rs");
+    let workspace = fs::read_to_string(&workspace_path)?;
+    let runner = fs::read_to_string(&runner_path)?;
+    let registry = fs::read_to_string(&days_path)?;
+
+    if registry.contains(&format!("number: {},", day)) {
+        return Err(CliError(format!("day {} is already registered in days.rs", day)).into());
+    }
+    let workspace = insert_before_list_end(
+        &workspace,
+        "members = [",
+        &format!("    \"{}/solution\",\n", dir),
+    )
+    .ok_or("cannot find the workspace members in Cargo.toml")?;
+    let runner = format!(
+        "{}{} = {{ path = \"../{}/solution\" }}\n",
+        runner, krate, dir
+    );
+    let registry = insert_before_list_end(
+        &registry,
+        "pub const DAYS: &[Day] = &[",
+        &format!(
+            "    Day {{\n        number: {},\n        dir: \"{}\",\n        solution: &{}::{},\n        stream: None,\n    }},\n",
+            day, dir, krate, name
+        ),
+    )
+    .ok_or("cannot find the DAYS table in aoc/src/days.rs")?;
+
+    let day_dir = root.join(&dir);
+    fs::create_dir_all(day_dir.join("solution/src"))?;
+    let files = [
+        ("topic.txt", format!("--- Day {}: {} ---\n", day, title)),
+        ("solution.md", solution_md(day, title)),
+        ("input.txt", String::new()),
+        ("output_part_one.txt", String::new()),
+        ("output_part_two.txt", String::new()),
+        ("example.txt", String::new()),
+        ("solution/Cargo.toml", cargo_toml(&krate)),
+        ("solution/src/lib.rs", lib_rs(day, &name)),
+    ];
+    for (file, contents) in files {
+        fs::write(day_dir.join(file), contents)?;
+    }
+
+    fs::write(&workspace_path, workspace)?;
+    fs::write(&runner_path, runner)?;
+    fs::write(&days_path, registry)?;
+    Ok(dir)
+}
+
+/// `text` with `item` inserted before the `]` closing the multi-line list that starts at the
+/// line `opening`.
+fn insert_before_list_end(text: &str, opening: &str, item: &str) -> Option<String> {
+    let start = text.find(opening)?;
+    let end = start + text[start..].find("\n]")? + 1;
+    Some(format!("{}{}{}", &text[..end], item, &text[end..]))
+}
+
+fn solution_md(day: u8, title: &str) -> String {
+    format!(
+        "# Advent of Code - Day {}: {}\n\n## Problem Summary\n\n## Part 1\n\n## Part 2\n",
+        day, title
+    )
+}
+
+fn cargo_toml(krate: &str) -> String {
+    format!(
+        "[package]\nname = \"{}\"\nversion.workspace = true\nedition.workspace = true\n\n\
+         [dependencies]\naoc-common = {{ path = \"../../common\" }}\n",
+        krate
+    )
+}
+
+fn lib_rs(day: u8, name: &str) -> String {
+    format!(
+        r#"use aoc_common::{{ParseError, Solution}};
+
+pub struct {name};
+
+impl Solution for {name} {{
+    const DAY: u8 = {day};
+    type Input = Vec<String>;
+    type Answer = u64;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {{
+        Ok(input.lines().map(str::to_string).collect())
+    }}
+
+    fn part_one(_lines: &Vec<String>) -> u64 {{
+        0
+    }}
+
+    fn part_two(_lines: &Vec<String>) -> u64 {{
+        0
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../../example.txt");
+
+    #[test]
+    #[ignore = "paste the example into example.txt and its answer here"]
+    fn part_one_example() {{
+        let input = {name}::parse(EXAMPLE).unwrap();
+        assert_eq!({name}::part_one(&input), 0);
+    }}
+
+    #[test]
+    #[ignore = "paste the example into example.txt and its answer here"]
+    fn part_two_example() {{
+        let input = {name}::parse(EXAMPLE).unwrap();
+        assert_eq!({name}::part_two(&input), 0);
+    }}
+}}
+"#
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn creates_and_registers_a_day_once() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("aoc/src")).unwrap();
+        fs::create_dir_all(root.join("01. First")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n]\n\n[workspace.package]\n",
+        )
+        .unwrap();
+        fs::write(root.join("aoc/Cargo.toml"), "[dependencies]\n").unwrap();
+        let registry = "pub const DAYS: &[Day] = &[\n];\n\npub fn find() {}\n";
+        fs::write(root.join("aoc/src/days.rs"), registry).unwrap();
+
+        assert_eq!(
+            scaffold(&root, 12, "Next Puzzle").unwrap(),
+            "12. Next Puzzle"
+        );
+
+        let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+        assert!(read("Cargo.toml").contains("    \"aoc\",\n    \"12. Next Puzzle/solution\",\n]"));
+        assert_eq!(
+            read("aoc/Cargo.toml"),
+            "[dependencies]\nday12 = { path = \"../12. Next Puzzle/solution\" }\n"
+        );
+        assert!(read("aoc/src/days.rs").contains("solution: &day12::Day12,"));
+        assert!(read("12. Next Puzzle/solution/src/lib.rs").contains("impl Solution for Day12"));
+        assert_eq!(read("12. Next Puzzle/input.txt"), "");
+
+        let before = read("aoc/src/days.rs");
+        let again = scaffold(&root, 12, "Other").unwrap_err().to_string();
+        assert!(
+            again.contains("already exists in '12. Next Puzzle'"),
+            "{}",
+            again
+        );
+        assert!(scaffold(&root, 1, "First").is_err());
+        assert_eq!(read("aoc/src/days.rs"), before);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,158 @@
+use std::error::Error;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::ExitCode;
+
+use aoc_common::{Part, Precision};
+
+use crate::cache;
+use crate::cli::VerifyArgs;
+use crate::days::{self, Day};
+
+pub enum Status {
+    Pass,
+    Fail,
+    /// No committed `output_part_*.txt` to compare against.
+    Missing,
+    /// The input could not be read or parsed, or the answer overflowed.
+    Error(String),
+}
+
+impl Status {
+    /// Compares a computed answer (or the reason there is none) with the committed one.
+    pub fn of(actual: &Result<String, String>, expected: &Option<String>) -> Status {
+        match (actual, expected) {
+            (Err(e), _) => Status::Error(e.clone()),
+            (Ok(_), None) => Status::Missing,
+            (Ok(a), Some(e)) if a == e => Status::Pass,
+            (Ok(_), Some(_)) => Status::Fail,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Missing => "missing",
+            Status::Error(_) => "error",
+        }
+    }
+}
+
+struct Check {
+    day: &'static Day,
+    part: Part,
+    expected: Option<String>,
+    actual: Option<String>,
+    status: Status,
+}
+
+/// Recomputes every selected day and part from its committed input and compares the answers
+/// with the committed output files, which are never written. Fails unless every check passes.
+pub fn verify(args: &VerifyArgs) -> Result<ExitCode, Box<dyn Error>> {
+    let selected = days::select(args.day)?;
+    let precision = if args.exact {
+        Precision::Exact
+    } else {
+        Precision::Fixed
+    };
+    let root = days::repo_root();
+    let mut checks = Vec::new();
+
+    for day in selected {
+        let input_path = day.input_path(&root);
+        let input = fs::read(&input_path)
+            .map_err(|e| format!("cannot read {}: {}", input_path.display(), e));
+        if let Ok(bytes) = &input
+            && let Some(warning) = cache::stale_warning(day, &root, bytes)
+        {
+            eprintln!("{}", warning);
+        }
+        let parsed = input.and_then(|bytes| {
+            day.solution
+                .parse_reader(&mut &bytes[..])
+                .map_err(|e| e.to_string())
+        });
+
+        for part in Part::BOTH {
+            let expected = read_expected(&day.output_path(&root, part))?;
+            let actual = parsed
+                .as_ref()
+                .map_err(|e| e.clone())
+                .and_then(|p| p.solve(part, precision).map_err(|e| e.to_string()));
+
+            let status = Status::of(&actual, &expected);
+            checks.push(Check {
+                day,
+                part,
+                expected,
+                actual: actual.ok(),
+                status,
+            });
+        }
+    }
+
+    print_table(&checks);
+
+    let passed = checks
+        .iter()
+        .filter(|c| matches!(c.status, Status::Pass))
+        .count();
+    println!("\n{} passed, {} failed", passed, checks.len() - passed);
+
+    Ok(if passed == checks.len() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    })
+}
+
+/// The trimmed contents of a committed answer file, or `None` if it is absent or empty.
+pub fn read_expected(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(s) if s.trim().is_empty() => Ok(None),
+        Ok(s) => Ok(Some(s.trim().to_string())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+fn print_table(checks: &[Check]) {
+    let rows: Vec<[String; 6]> = checks
+        .iter()
+        .map(|c| {
+            [
+                c.day.number.to_string(),
+                c.day.title().to_string(),
+                c.part.to_string(),
+                c.expected.clone().unwrap_or_else(|| "-".to_string()),
+                c.actual.clone().unwrap_or_else(|| "-".to_string()),
+                c.status.name().to_uppercase(),
+            ]
+        })
+        .collect();
+
+    let header = ["Day", "Title", "Part", "Expected", "Actual", "Status"].map(String::from);
+    let mut widths = header.clone().map(|h| h.len());
+    for row in &rows {
+        for (w, cell) in widths.iter_mut().zip(row) {
+            *w = (*w).max(cell.len());
+        }
+    }
+
+    for row in std::iter::once(&header).chain(&rows) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths)
+            .map(|(cell, w)| format!("{:<w$}", cell, w = w))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+
+    for c in checks {
+        if let Status::Error(e) = &c.status {
+            println!("\nday {} part {}: {}", c.day.number, c.part, e);
+        }
+    }
+}
@@ -0,0 +1,312 @@
+//! Arbitrary-precision unsigned integers for answers that do not fit in a machine word, and the
+//! [`Natural`] trait that lets a solver run the same code in fixed width or exactly.
+
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, Mul, Sub};
+
+use crate::Overflow;
+
+/// Limbs hold nine decimal digits each, which keeps formatting and parsing trivial.
+const BASE: u64 = 1_000_000_000;
+
+/// An unsigned integer of any size.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Little-endian base-10⁹ limbs without trailing zeros; zero has none.
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        BigUint::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    fn trim(mut self) -> Self {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+        self
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        BigUint::from(n as u128)
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut n: u128) -> Self {
+        let mut limbs = Vec::new();
+        while n > 0 {
+            limbs.push((n % BASE as u128) as u32);
+            n /= BASE as u128;
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Add for &BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: &BigUint) -> BigUint {
+        let mut limbs = Vec::with_capacity(self.limbs.len().max(rhs.limbs.len()) + 1);
+        let mut carry = 0;
+        for i in 0..self.limbs.len().max(rhs.limbs.len()) {
+            let sum = carry
+                + self.limbs.get(i).copied().unwrap_or(0) as u64
+                + rhs.limbs.get(i).copied().unwrap_or(0) as u64;
+            limbs.push((sum % BASE) as u32);
+            carry = sum / BASE;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+        BigUint { limbs }
+    }
+}
+
+impl Add for BigUint {
+    type Output = BigUint;
+
+    fn add(self, rhs: BigUint) -> BigUint {
+        &self + &rhs
+    }
+}
+
+impl Sub for &BigUint {
+    type Output = BigUint;
+
+    /// Panics if `rhs` is larger, like the fixed-width integers in debug builds.
+    fn sub(self, rhs: &BigUint) -> BigUint {
+        assert!(*self >= *rhs, "attempt to subtract with overflow");
+        let mut limbs = Vec::with_capacity(self.limbs.len());
+        let mut borrow = 0;
+        for (i, &limb) in self.limbs.iter().enumerate() {
+            let sub = borrow + rhs.limbs.get(i).copied().unwrap_or(0) as u64;
+            let (diff, under) = (limb as u64).overflowing_sub(sub);
+            limbs.push(if under { diff.wrapping_add(BASE) } else { diff } as u32);
+            borrow = under as u64;
+        }
+        BigUint { limbs }.trim()
+    }
+}
+
+impl Sub for BigUint {
+    type Output = BigUint;
+
+    fn sub(self, rhs: BigUint) -> BigUint {
+        &self - &rhs
+    }
+}
+
+impl Mul for &BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: &BigUint) -> BigUint {
+        if self.is_zero() || rhs.is_zero() {
+            return BigUint::zero();
+        }
+        let mut acc = vec![0u64; self.limbs.len() + rhs.limbs.len()];
+        for (i, &a) in self.limbs.iter().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in rhs.limbs.iter().enumerate() {
+                let cur = acc[i + j] + a as u64 * b as u64 + carry;
+                acc[i + j] = cur % BASE;
+                carry = cur / BASE;
+            }
+            acc[i + rhs.limbs.len()] += carry;
+        }
+        BigUint {
+            limbs: acc.into_iter().map(|limb| limb as u32).collect(),
+        }
+        .trim()
+    }
+}
+
+impl Mul for BigUint {
+    type Output = BigUint;
+
+    fn mul(self, rhs: BigUint) -> BigUint {
+        &self * &rhs
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs
+            .len()
+            .cmp(&other.limbs.len())
+            .then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some((top, rest)) = self.limbs.split_last() else {
+            return f.pad_integral(true, "", "0");
+        };
+        let mut digits = top.to_string();
+        for limb in rest.iter().rev() {
+            digits.push_str(&format!("{:09}", limb));
+        }
+        f.pad_integral(true, "", &digits)
+    }
+}
+
+/// Counting arithmetic shared by the fixed-width integers and [`BigUint`]: solvers written
+/// against it report [`Overflow`] in fixed width and are exact with `BigUint`.
+pub trait Natural: Clone + Ord + fmt::Display + Sized {
+    fn from_u64(n: u64) -> Self;
+    fn checked_add(&self, rhs: &Self) -> Result<Self, Overflow>;
+    fn checked_mul(&self, rhs: &Self) -> Result<Self, Overflow>;
+
+    /// `self - rhs`, or `None` if that would be negative.
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+
+    fn zero() -> Self {
+        Self::from_u64(0)
+    }
+
+    fn one() -> Self {
+        Self::from_u64(1)
+    }
+
+    /// Converts a `u128`, which may not fit in narrower widths.
+    fn from_u128(n: u128) -> Result<Self, Overflow> {
+        let (high, low) = (Self::from_u64((n >> 64) as u64), Self::from_u64(n as u64));
+        let half = Self::from_u64(1 << 32);
+        high.checked_mul(&half)?
+            .checked_mul(&half)?
+            .checked_add(&low)
+    }
+
+    /// The value of a string of ASCII decimal digits.
+    fn from_digits(digits: &[u8]) -> Result<Self, Overflow> {
+        let ten = Self::from_u64(10);
+        digits.iter().try_fold(Self::zero(), |acc, &d| {
+            debug_assert!(d.is_ascii_digit());
+            acc.checked_mul(&ten)?
+                .checked_add(&Self::from_u64((d - b'0') as u64))
+        })
+    }
+}
+
+macro_rules! fixed_width_natural {
+    ($($t:ty),*) => {$(
+        impl Natural for $t {
+            fn from_u64(n: u64) -> Self {
+                n as $t
+            }
+
+            fn checked_add(&self, rhs: &Self) -> Result<Self, Overflow> {
+                <$t>::checked_add(*self, *rhs).ok_or(Overflow { width: stringify!($t) })
+            }
+
+            fn checked_mul(&self, rhs: &Self) -> Result<Self, Overflow> {
+                <$t>::checked_mul(*self, *rhs).ok_or(Overflow { width: stringify!($t) })
+            }
+
+            fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+                <$t>::checked_sub(*self, *rhs)
+            }
+        }
+    )*};
+}
+
+fixed_width_natural!(u64, u128);
+
+impl Natural for BigUint {
+    fn from_u64(n: u64) -> Self {
+        BigUint::from(n)
+    }
+
+    fn checked_add(&self, rhs: &Self) -> Result<Self, Overflow> {
+        Ok(self + rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Result<Self, Overflow> {
+        Ok(self * rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        (self >= rhs).then(|| self - rhs)
+    }
+
+    fn from_u128(n: u128) -> Result<Self, Overflow> {
+        Ok(BigUint::from(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn big(s: &str) -> BigUint {
+        BigUint::from_digits(s.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn formats_across_limbs() {
+        assert_eq!(BigUint::zero().to_string(), "0");
+        assert_eq!(BigUint::from(1_000_000_007u64).to_string(), "1000000007");
+        assert_eq!(BigUint::from(u128::MAX).to_string(), u128::MAX.to_string());
+        assert_eq!(format!("{:>5}", BigUint::from(42u64)), "   42");
+    }
+
+    #[test]
+    fn arithmetic_matches_u128() {
+        let (a, b) = (0xffff_ffff_ffff_fffdu64, 1_000_000_000_000_000_003u64);
+        let (x, y) = (BigUint::from(a), BigUint::from(b));
+        assert_eq!(&x + &y, BigUint::from(a as u128 + b as u128));
+        assert_eq!(&x * &y, BigUint::from(a as u128 * b as u128));
+        assert_eq!(&x * &BigUint::zero(), BigUint::zero());
+        assert_eq!(&x - &y, BigUint::from(a - b));
+        assert_eq!(&x - &x, BigUint::zero());
+        assert_eq!(Natural::checked_sub(&y, &x), None);
+        assert_eq!(x.cmp(&y), a.cmp(&b));
+    }
+
+    #[test]
+    fn exceeds_u128() {
+        let max = BigUint::from(u128::MAX);
+        let square = &max * &max;
+        assert_eq!(
+            square.to_string(),
+            "115792089237316195423570985008687907852589419931798687112530834793049593217025"
+        );
+        assert!(square > max);
+        assert_eq!(
+            big("340282366920938463463374607431768211456"),
+            &max + &BigUint::from(1u64)
+        );
+        assert_eq!(&(&max + &BigUint::from(1u64)) - &BigUint::from(1u64), max);
+    }
+
+    #[test]
+    fn fixed_width_reports_overflow() {
+        assert_eq!(
+            Natural::checked_add(&u64::MAX, &1),
+            Err(Overflow { width: "u64" })
+        );
+        assert_eq!(
+            u128::from_digits(b"340282366920938463463374607431768211456"),
+            Err(Overflow { width: "u128" })
+        );
+        assert_eq!(u64::from_digits(b"0042"), Ok(42));
+        assert_eq!(u64::from_u128(u64::MAX as u128), Ok(u64::MAX));
+        assert_eq!(u64::from_u128(1 << 64), Err(Overflow { width: "u64" }));
+        assert_eq!(u128::from_u128(u128::MAX), Ok(u128::MAX));
+    }
+}
@@ -0,0 +1,111 @@
+use std::error::Error;
+use std::fmt;
+
+/// A malformed puzzle input, located down to the offending text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u8,
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in characters, where the offending text starts.
+    pub column: usize,
+    /// Length of the offending text in characters (at least 1).
+    pub len: usize,
+    /// The full offending line, without its line terminator.
+    pub line_text: String,
+    pub message: String,
+}
+
+impl ParseError {
+    /// An error covering `part`, which must be a subslice of `line` (e.g. obtained by
+    /// splitting or trimming it). `line_no` is 1-based.
+    pub fn at(day: u8, line_no: usize, line: &str, part: &str, message: impl Into<String>) -> Self {
+        let start = (part.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|&offset| offset + part.len() <= line.len())
+            .expect("`part` must be a subslice of `line`");
+        Self::at_byte(day, line_no, line, start, part.len(), message)
+    }
+
+    /// An error covering `len` bytes of `line` starting at byte offset `start`.
+    pub fn at_byte(
+        day: u8,
+        line_no: usize,
+        line: &str,
+        start: usize,
+        len: usize,
+        message: impl Into<String>,
+    ) -> Self {
+        let column = line[..start].chars().count() + 1;
+        let len = line[start..start + len].chars().count().max(1);
+        ParseError {
+            day,
+            line: line_no,
+            column,
+            len,
+            line_text: line.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// An error pointing just past the end of `line`, for text that is missing.
+    pub fn at_end(day: u8, line_no: usize, line: &str, message: impl Into<String>) -> Self {
+        Self::at_byte(day, line_no, line, line.len(), 0, message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+/// An answer that does not fit in the fixed-width integer a solver computes it in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow {
+    /// The integer type that overflowed, e.g. `"u64"`.
+    pub width: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the answer does not fit in {}; rerun with --exact for an arbitrary-precision answer",
+            self.width
+        )
+    }
+}
+
+impl Error for Overflow {}
+
+/// Why a part has no answer for an input that parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswerError {
+    Overflow(Overflow),
+    /// The input is fine for the other part, but this part cannot be answered from it.
+    Unanswerable(String),
+}
+
+impl From<Overflow> for AnswerError {
+    fn from(e: Overflow) -> Self {
+        AnswerError::Overflow(e)
+    }
+}
+
+impl fmt::Display for AnswerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswerError::Overflow(e) => e.fmt(f),
+            AnswerError::Unanswerable(why) => f.write_str(why),
+        }
+    }
+}
+
+impl Error for AnswerError {}
@@ -0,0 +1,199 @@
+//! Worked examples embedded in a puzzle description saved as plain text.
+//!
+//! An example input is introduced by a sentence starting with "For example" and ending in a
+//! colon, then a blank line; it runs up to the next line of prose, or the blank line before one.
+//! A sentence that mentions numbers ("For example, a rectangle between 2,5 and 9,7:") shows an
+//! answer rather than an input, so it introduces nothing. [`from_topic`] takes the first input of
+//! a part's section (part two falls back to part one's, since it usually says "consider again
+//! the example") and the last number stated before the section's closing question as its answer.
+
+/// An example input with the answer the puzzle description gives for it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Example {
+    pub input: String,
+    pub answer: String,
+}
+
+/// The examples found for each part of a day; a part is `None` if no input or answer was found.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopicExamples {
+    pub part_one: Option<Example>,
+    pub part_two: Option<Example>,
+}
+
+const PART_TWO_HEADING: &str = "--- Part Two ---";
+
+/// Extracts both parts' examples from a plain-text puzzle description.
+pub fn from_topic(topic: &str) -> TopicExamples {
+    let (first, second) = match topic.find(PART_TWO_HEADING) {
+        Some(at) => (&topic[..at], Some(&topic[at..])),
+        None => (topic, None),
+    };
+
+    let input_one = inputs(first).into_iter().next();
+    let part_one = input_one
+        .clone()
+        .zip(last_answer(first))
+        .map(|(input, answer)| Example { input, answer });
+
+    let part_two = second.and_then(|section| {
+        let input = inputs(section).into_iter().next().or(input_one)?;
+        let answer = last_answer(section)?;
+        Some(Example { input, answer })
+    });
+
+    TopicExamples { part_one, part_two }
+}
+
+/// Every example input in `text`, in order. An input the description wraps "for legibility" is
+/// joined back into one line.
+pub fn inputs(text: &str) -> Vec<String> {
+    let lines: Vec<&str> = text.lines().collect();
+    let mut found = Vec::new();
+    let mut i = 0;
+    while i + 2 < lines.len() {
+        if !introduces_input(lines[i]) || !lines[i + 1].trim().is_empty() {
+            i += 1;
+            continue;
+        }
+        let start = i + 2;
+        let mut end = start;
+        // A blank line inside an input (between the sections of a database, say) is kept.
+        let part_of_input = |l: &str| !l.trim().is_empty() && !is_prose(l);
+        while end < lines.len()
+            && (part_of_input(lines[end])
+                || end > start && lines.get(end + 1).is_some_and(|l| part_of_input(l)))
+        {
+            end += 1;
+        }
+        if end > start {
+            let block = &lines[start..end];
+            let wrapped = lines.get(end).is_some_and(|l| l.contains("wrapped"));
+            found.push(if wrapped {
+                format!("{}\n", block.concat())
+            } else {
+                block.iter().map(|l| format!("{}\n", l)).collect()
+            });
+        }
+        i = end;
+    }
+    found
+}
+
+fn introduces_input(line: &str) -> bool {
+    let intro = sentences(line).last().unwrap_or("");
+    intro.starts_with("For example")
+        && intro.ends_with(':')
+        && !intro.bytes().any(|b| b.is_ascii_digit())
+}
+
+/// Whether `line` is a sentence of the description rather than a line of an input or diagram.
+fn is_prose(line: &str) -> bool {
+    let line = line.trim();
+    line.contains(' ') && line.ends_with(['.', ':', '?', '!', ')'])
+}
+
+/// The sentences of one line of prose, each with its closing punctuation.
+fn sentences(line: &str) -> impl Iterator<Item = &str> {
+    let line = line.trim();
+    let mut rest = line;
+    std::iter::from_fn(move || {
+        if rest.is_empty() {
+            return None;
+        }
+        let end = rest
+            .match_indices(['.', ':', '?', '!'])
+            .map(|(at, _)| at + 1)
+            .find(|&at| rest[at..].starts_with(' '))
+            .unwrap_or(rest.len());
+        let sentence = &rest[..end];
+        rest = rest[end..].trim_start();
+        Some(sentence)
+    })
+}
+
+/// The last number in the last sentence stating one before the section's closing question.
+/// Passed over on the way: asides starting with "Be careful", and a sentence that ends a line
+/// by introducing a diagram ("One way to do this is between 2,5 and 11,1:").
+fn last_answer(text: &str) -> Option<String> {
+    let mut prose: Vec<&str> = text.lines().filter(|l| is_prose(l)).collect();
+    while prose.last().is_some_and(|l| l.trim_end().ends_with('?')) {
+        prose.pop();
+    }
+
+    prose
+        .iter()
+        .rev()
+        .filter(|line| !line.trim_start().starts_with("Be careful"))
+        .find_map(|line| {
+            let mut stated: Vec<&str> = sentences(line).collect();
+            if stated.len() > 1 && stated.last().is_some_and(|s| s.ends_with(':')) {
+                stated.pop();
+            }
+            stated.iter().rev().find_map(|s| last_number(s))
+        })
+}
+
+fn last_number(sentence: &str) -> Option<String> {
+    sentence
+        .split(|c: char| !c.is_ascii_digit())
+        .rfind(|run| !run.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOPIC: &str = "--- Day 1: Test ---\n\
+        The dial has 100 positions. For example, suppose you had these rotations:\n\
+        \n\
+        L68\n\
+        R48\n\
+        Following them, the dial points at 0 once. In this example, the password is 3.\n\
+        \n\
+        What is the password?\n\
+        \n\
+        --- Part Two ---\n\
+        Now the password is method 0x43, i.e. 6 in this example.\n\
+        \n\
+        Be careful: R1000 would point at 0 ten times!\n\
+        \n\
+        What is the new password?\n";
+
+    #[test]
+    fn finds_inputs_and_answers() {
+        let examples = from_topic(TOPIC);
+        let expected = |answer: &str| Example {
+            input: "L68\nR48\n".to_string(),
+            answer: answer.to_string(),
+        };
+        assert_eq!(examples.part_one, Some(expected("3")));
+        assert_eq!(examples.part_two, Some(expected("6")));
+    }
+
+    #[test]
+    fn part_two_prefers_its_own_input() {
+        let topic = TOPIC.replace(
+            "Now the password",
+            "For example:\n\n  a b\n  c d\nNow the password",
+        );
+        let part_two = from_topic(&topic).part_two.unwrap();
+        assert_eq!(part_two.input, "  a b\n  c d\n");
+    }
+
+    #[test]
+    fn skips_illustrations_and_joins_wrapped_inputs() {
+        let topic = "For example:\n\n1-2,\n3-4\n(The ranges are wrapped here for legibility.)\n\n\
+            For example, the tiles between 1,1 and 2,2:\n\n#.\n.#\n\
+            The largest area is 50. One way is between 2,5 and 11,1:\n\n..\n";
+        assert_eq!(inputs(topic), ["1-2,3-4\n"]);
+        assert_eq!(from_topic(topic).part_one.unwrap().answer, "50");
+    }
+
+    #[test]
+    fn descriptions_without_examples_have_none() {
+        let topic = "--- Day 1 ---\nThe dial starts at 50.\n\n--- Part Two ---\nSix.";
+        assert_eq!(from_topic(topic), TopicExamples::default());
+    }
+}
@@ -0,0 +1,313 @@
+//! Rectangular character grids, stored row-major in one flat buffer.
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::ParseError;
+
+/// A cell position: `row` lines down from the top, `col` characters right from the left.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Pos {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Pos {
+    pub const fn new(row: usize, col: usize) -> Self {
+        Pos { row, col }
+    }
+}
+
+/// Which cells around a position count as its neighbours.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Connectivity {
+    /// Up, left, right and down.
+    Four,
+    /// The four orthogonal neighbours and the four diagonal ones.
+    Eight,
+}
+
+impl Connectivity {
+    /// `(row, col)` offsets of the neighbours, in reading order.
+    pub fn offsets(self) -> &'static [(isize, isize)] {
+        match self {
+            Connectivity::Four => &[(-1, 0), (0, -1), (0, 1), (1, 0)],
+            Connectivity::Eight => &[
+                (-1, -1),
+                (-1, 0),
+                (-1, 1),
+                (0, -1),
+                (0, 1),
+                (1, -1),
+                (1, 0),
+                (1, 1),
+            ],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one cell per character of every non-empty line. `cell` maps a character to its
+    /// value, or to `None` if it is not allowed, in which case the error says the cell was
+    /// `expected` to be something else (e.g. `"'@' or '.'"`). Lines must all be as wide as the
+    /// first one.
+    pub fn parse_with(
+        day: u8,
+        input: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for (i, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let mut len = 0;
+            for (j, c) in line.char_indices() {
+                let Some(value) = cell(c) else {
+                    let msg = format!("invalid cell '{}', expected {}", c, expected);
+                    return Err(ParseError::at_byte(day, i + 1, line, j, c.len_utf8(), msg));
+                };
+                cells.push(value);
+                len += 1;
+            }
+            let width = *width.get_or_insert(len);
+            if len != width {
+                let msg = format!("expected {} columns, found {}", width, len);
+                return Err(ParseError::at(day, i + 1, line, line, msg));
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        pos.row < self.height && pos.col < self.width
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.contains(pos)
+            .then(|| &self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.contains(pos)
+            .then(|| &mut self.cells[pos.row * self.width + pos.col])
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> + use<T> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Pos { row, col }))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// The first position, row by row, holding `value`.
+    pub fn find(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|&(_, v)| v == value).map(|(pos, _)| pos)
+    }
+
+    /// `pos` moved by `(dr, dc)`, if that is still inside the grid.
+    pub fn offset(&self, pos: Pos, (dr, dc): (isize, isize)) -> Option<Pos> {
+        let moved = Pos {
+            row: pos.row.checked_add_signed(dr)?,
+            col: pos.col.checked_add_signed(dc)?,
+        };
+        self.contains(moved).then_some(moved)
+    }
+
+    /// The neighbours of `pos` that lie inside the grid.
+    pub fn neighbors(&self, pos: Pos, connectivity: Connectivity) -> impl Iterator<Item = Pos> {
+        connectivity
+            .offsets()
+            .iter()
+            .filter_map(move |&offset| self.offset(pos, offset))
+    }
+
+    /// The neighbours of `pos` on a torus: leaving one edge re-enters at the opposite one.
+    /// On grids narrower or shorter than three cells a neighbour can appear more than once; an
+    /// empty grid has none.
+    pub fn neighbors_wrapping(
+        &self,
+        pos: Pos,
+        connectivity: Connectivity,
+    ) -> impl Iterator<Item = Pos> + use<T> {
+        let (width, height) = (self.width as isize, self.height as isize);
+        let offsets = if self.is_empty() {
+            &[][..]
+        } else {
+            connectivity.offsets()
+        };
+        offsets.iter().map(move |&(dr, dc)| Pos {
+            row: (pos.row as isize + dr).rem_euclid(height) as usize,
+            col: (pos.col as isize + dc).rem_euclid(width) as usize,
+        })
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid whose cells are the characters in `allowed`.
+    pub fn parse(day: u8, input: &str, allowed: &str) -> Result<Self, ParseError> {
+        let quoted: Vec<String> = allowed.chars().map(|c| format!("'{}'", c)).collect();
+        let expected = match quoted.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} or {}", rest.join(", "), last),
+            _ => quoted.concat(),
+        };
+        Self::parse_with(day, input, &expected, |c| allowed.contains(c).then_some(c))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} is outside a {}x{} grid", pos, width, height))
+    }
+}
+
+/// One line per row with the cells written next to each other, e.g. the puzzle's own text for
+/// a `Grid<char>`.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, row) in self.rows().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "..@\n@S.\n\n";
+
+    #[test]
+    fn parses_and_prints() {
+        let grid = Grid::parse(4, TEXT, ".@S").unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Pos::new(0, 2)], '@');
+        assert_eq!(grid.find(&'S'), Some(Pos::new(1, 1)));
+        assert_eq!(grid.find(&'#'), None);
+        assert_eq!(grid.to_string(), "..@\n@S.");
+        assert_eq!(grid.map(|&c| u8::from(c == '@')).to_string(), "001\n100");
+    }
+
+    #[test]
+    fn rejects_ragged_lines_and_unknown_cells() {
+        let err = Grid::parse(4, "...\n..\n", ".@").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.message, "expected 3 columns, found 2");
+
+        let err = Grid::parse(7, "..\n.x\n", ".^S").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+        assert_eq!(err.message, "invalid cell 'x', expected '.', '^' or 'S'");
+    }
+
+    #[test]
+    fn neighbors_stay_inside_or_wrap() {
+        let grid = Grid::new(3, 2, 0u8);
+        let corner = Pos::new(0, 0);
+        let bounded: Vec<Pos> = grid.neighbors(corner, Connectivity::Four).collect();
+        assert_eq!(bounded, [Pos::new(0, 1), Pos::new(1, 0)]);
+        assert_eq!(grid.neighbors(corner, Connectivity::Eight).count(), 3);
+        assert_eq!(
+            grid.neighbors(Pos::new(1, 1), Connectivity::Eight).count(),
+            5
+        );
+
+        let wrapped: Vec<Pos> = grid
+            .neighbors_wrapping(corner, Connectivity::Four)
+            .collect();
+        assert_eq!(
+            wrapped,
+            [
+                Pos::new(1, 0),
+                Pos::new(0, 2),
+                Pos::new(0, 1),
+                Pos::new(1, 0)
+            ]
+        );
+
+        for empty in [Grid::new(0, 2, 0u8), Grid::new(3, 0, 0u8)] {
+            let around = empty.neighbors_wrapping(corner, Connectivity::Eight);
+            assert_eq!(around.count(), 0);
+        }
+    }
+}
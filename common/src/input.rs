@@ -0,0 +1,138 @@
+//! Reading puzzle input from any [`BufRead`] (a file, stdin or a generator), a line at a time.
+//!
+//! Line-oriented days parse through [`for_each_line`], which reuses one buffer, so the raw text
+//! is never held in memory. Days implementing [`Streaming`] go further and fold each line into
+//! a fixed-size state, which lets them answer inputs much larger than RAM.
+
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::{AnswerError, IntoAnswer, ParseError, Solution};
+
+/// A puzzle input that could not be read or did not parse.
+#[derive(Debug)]
+pub enum InputError {
+    Io(io::Error),
+    Parse(ParseError),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "cannot read input: {}", e),
+            InputError::Parse(e) => e.fmt(f),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+impl From<io::Error> for InputError {
+    fn from(e: io::Error) -> Self {
+        InputError::Io(e)
+    }
+}
+
+impl From<ParseError> for InputError {
+    fn from(e: ParseError) -> Self {
+        InputError::Parse(e)
+    }
+}
+
+/// Calls `f` with the 1-based number and text of every line, without its `\n` or `\r\n`
+/// terminator, exactly as [`str::lines`] would split the whole input.
+pub fn for_each_line(
+    reader: &mut dyn BufRead,
+    mut f: impl FnMut(usize, &str) -> Result<(), ParseError>,
+) -> Result<(), InputError> {
+    let mut buf = String::new();
+    let mut line_no = 0;
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
+            return Ok(());
+        }
+        line_no += 1;
+        let line = match buf.strip_suffix('\n') {
+            Some(line) => line.strip_suffix('\r').unwrap_or(line),
+            None => &buf,
+        };
+        f(line_no, line)?;
+    }
+}
+
+/// Runs a reader-based parser on in-memory text, for days whose [`Solution::parse`] is
+/// implemented on top of [`Solution::parse_reader`].
+pub fn parse_str<T>(
+    text: &str,
+    parse: impl FnOnce(&mut dyn BufRead) -> Result<T, InputError>,
+) -> Result<T, ParseError> {
+    parse(&mut text.as_bytes()).map_err(|e| match e {
+        InputError::Parse(e) => e,
+        InputError::Io(e) => unreachable!("reading from memory cannot fail: {}", e),
+    })
+}
+
+/// A day whose two answers can be computed in a single pass over the input's lines while
+/// holding only a bounded amount of state.
+pub trait Streaming: Solution {
+    /// Everything remembered between lines; its size must not grow with the number of lines.
+    type State: Default;
+
+    fn feed(state: &mut Self::State, line_no: usize, line: &str) -> Result<(), ParseError>;
+
+    /// Part one's and part two's answers once every line has been fed.
+    fn finish(state: Self::State) -> [Self::Answer; 2];
+}
+
+/// Both answers of a [`Streaming`] day, reading `reader` to the end a line at a time.
+pub fn stream<S: Streaming>(reader: &mut dyn BufRead) -> Result<[S::Answer; 2], InputError> {
+    let mut state = S::State::default();
+    for_each_line(reader, |line_no, line| S::feed(&mut state, line_no, line))?;
+    Ok(S::finish(state))
+}
+
+/// [`stream`] with the answers formatted as text, so streaming days of different answer types
+/// can sit in one registry.
+pub type StreamFn = fn(&mut dyn BufRead) -> Result<[Result<String, AnswerError>; 2], InputError>;
+
+/// The [`StreamFn`] of a streaming day.
+pub fn stream_answers<S: Streaming>(
+    reader: &mut dyn BufRead,
+) -> Result<[Result<String, AnswerError>; 2], InputError> {
+    Ok(stream::<S>(reader)?.map(IntoAnswer::into_answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lines(text: &[u8]) -> Vec<(usize, String)> {
+        let mut out = Vec::new();
+        for_each_line(&mut &text[..], |n, line| {
+            out.push((n, line.to_string()));
+            Ok(())
+        })
+        .unwrap();
+        out
+    }
+
+    #[test]
+    fn splits_like_str_lines() {
+        for text in ["a\nb\n", "a\r\nb", "\n\nc\r", "", "x\r\n\r\ny\n\n"] {
+            let expected: Vec<(usize, String)> = text
+                .lines()
+                .enumerate()
+                .map(|(i, l)| (i + 1, l.to_string()))
+                .collect();
+            assert_eq!(lines(text.as_bytes()), expected, "{:?}", text);
+        }
+    }
+
+    #[test]
+    fn reports_invalid_utf8_as_io_error() {
+        let result = for_each_line(&mut &b"ok\n\xff\n"[..], |_, _| Ok(()));
+        assert!(matches!(result, Err(InputError::Io(_))));
+    }
+}
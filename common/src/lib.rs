@@ -0,0 +1,171 @@
+use std::fmt;
+use std::io::BufRead;
+
+pub mod bignum;
+mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod testing;
+pub mod trace;
+
+pub use bignum::{BigUint, Natural};
+pub use error::{AnswerError, Overflow, ParseError};
+pub use grid::{Connectivity, Grid, Pos};
+pub use input::{InputError, Streaming};
+
+/// One of the two puzzles of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    /// The part's number as written on the puzzle page (1 or 2).
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(n: u8) -> Option<Part> {
+        match n {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+/// A day's puzzle: parse the raw input once, then answer both parts from the parsed model.
+///
+/// Implementations do no file I/O, so answers can be computed in-memory from tests,
+/// benchmarks and the runner alike.
+pub trait Solution {
+    /// The puzzle's day number, reported in parse errors.
+    const DAY: u8;
+    /// Part of the runner's answer cache key: bump it whenever a change to the solver could
+    /// change its answers, so stale cached answers are not served.
+    const VERSION: &'static str = "1";
+    /// The parsed puzzle input shared by both parts.
+    type Input;
+    /// The answer type of both parts: a number, or `Result<_, AnswerError>` (or
+    /// `Result<_, Overflow>`) for days whose arithmetic can overflow on large inputs or whose
+    /// parts cannot always be answered from the same input.
+    type Answer: IntoAnswer;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses input from a reader such as stdin. The default reads it all and calls
+    /// [`Solution::parse`]; line-oriented days override it to parse a line at a time with
+    /// [`input::for_each_line`] and implement `parse` with [`input::parse_str`].
+    fn parse_reader(reader: &mut dyn BufRead) -> Result<Self::Input, InputError> {
+        let mut text = String::new();
+        reader.read_to_string(&mut text)?;
+        Ok(Self::parse(&text)?)
+    }
+    fn part_one(input: &Self::Input) -> Self::Answer;
+    fn part_two(input: &Self::Input) -> Self::Answer;
+
+    /// Part one in arbitrary precision, for days that can overflow; `None` if the fixed-width
+    /// answer is always exact.
+    fn part_one_exact(_input: &Self::Input) -> Option<BigUint> {
+        None
+    }
+
+    /// Part two in arbitrary precision; see [`Solution::part_one_exact`].
+    fn part_two_exact(_input: &Self::Input) -> Option<BigUint> {
+        None
+    }
+}
+
+/// How a day's answer is computed: in the solver's fixed-width integers (the default, failing
+/// with [`Overflow`] if they are too small) or exactly with [`BigUint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Precision {
+    #[default]
+    Fixed,
+    Exact,
+}
+
+/// An answer as printed and compared with the committed outputs.
+pub trait IntoAnswer {
+    fn into_answer(self) -> Result<String, AnswerError>;
+}
+
+macro_rules! display_answer {
+    ($($t:ty),*) => {$(
+        impl IntoAnswer for $t {
+            fn into_answer(self) -> Result<String, AnswerError> {
+                Ok(self.to_string())
+            }
+        }
+    )*};
+}
+
+display_answer!(i64, u64, u128, BigUint);
+
+impl<T: IntoAnswer, E: Into<AnswerError>> IntoAnswer for Result<T, E> {
+    fn into_answer(self) -> Result<String, AnswerError> {
+        self.map_err(Into::into)?.into_answer()
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days with different input and answer types can sit
+/// in one registry. Every `Solution` gets it for free.
+pub trait DynSolution: Sync {
+    fn version(&self) -> &'static str;
+    fn parse(&self, input: &str) -> Result<Box<dyn ParsedInput>, ParseError>;
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Box<dyn ParsedInput>, InputError>;
+}
+
+/// A parsed input whose parts can be solved with their answers formatted as text.
+pub trait ParsedInput {
+    /// With [`Precision::Exact`], days without an exact variant use their fixed-width answer.
+    fn solve(&self, part: Part, precision: Precision) -> Result<String, AnswerError>;
+}
+
+struct Parsed<S: Solution>(S::Input);
+
+impl<S: Solution> ParsedInput for Parsed<S> {
+    fn solve(&self, part: Part, precision: Precision) -> Result<String, AnswerError> {
+        let exact = match (precision, part) {
+            (Precision::Fixed, _) => None,
+            (Precision::Exact, Part::One) => S::part_one_exact(&self.0),
+            (Precision::Exact, Part::Two) => S::part_two_exact(&self.0),
+        };
+        match (exact, part) {
+            (Some(answer), _) => Ok(answer.to_string()),
+            (None, Part::One) => S::part_one(&self.0).into_answer(),
+            (None, Part::Two) => S::part_two(&self.0).into_answer(),
+        }
+    }
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync + 'static,
+    S::Input: 'static,
+{
+    fn version(&self) -> &'static str {
+        S::VERSION
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn ParsedInput>, ParseError> {
+        Ok(Box::new(Parsed::<S>(S::parse(input)?)))
+    }
+
+    fn parse_reader(&self, reader: &mut dyn BufRead) -> Result<Box<dyn ParsedInput>, InputError> {
+        Ok(Box::new(Parsed::<S>(S::parse_reader(reader)?)))
+    }
+}
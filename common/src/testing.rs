@@ -0,0 +1,182 @@
+//! Seeded random inputs for differential tests: a solver is run against a naive reference
+//! implementation on many generated inputs, and a failing input is shrunk before it is reported.
+//!
+//! Runs are reproducible. The seed defaults to a fixed value and can be changed with the
+//! `AOC_SEED` environment variable to explore other inputs.
+
+use std::fmt::Debug;
+
+const DEFAULT_SEED: u64 = 0x2025_1201_ad7e_c0de;
+
+/// A small SplitMix64 generator; not for anything but tests.
+#[derive(Debug, Clone)]
+pub struct Rng(u64);
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `lo..=hi`.
+    pub fn range(&mut self, lo: u64, hi: u64) -> u64 {
+        assert!(lo <= hi, "empty range {}..={}", lo, hi);
+        match (hi - lo).checked_add(1) {
+            Some(span) => lo + self.next_u64() % span,
+            None => self.next_u64(),
+        }
+    }
+
+    /// A length or index in `lo..=hi`.
+    pub fn usize(&mut self, lo: usize, hi: usize) -> usize {
+        self.range(lo as u64, hi as u64) as usize
+    }
+
+    /// `true` with probability `1 / n`.
+    pub fn one_in(&mut self, n: u64) -> bool {
+        self.next_u64().is_multiple_of(n)
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.usize(0, items.len() - 1)]
+    }
+}
+
+/// Checks `property` on `cases` inputs from `generate`. On the first failure the input is shrunk
+/// greedily (keeping any candidate from `shrink` that still fails) and the test panics with the
+/// seed, the minimized input and the property's description of the mismatch.
+pub fn check<T: Clone + Debug>(
+    name: &str,
+    cases: usize,
+    mut generate: impl FnMut(&mut Rng) -> T,
+    shrink: impl Fn(&T) -> Vec<T>,
+    property: impl Fn(&T) -> Result<(), String>,
+) {
+    let seed = std::env::var("AOC_SEED")
+        .ok()
+        .and_then(|s| s.parse().ok())
+        .unwrap_or(DEFAULT_SEED);
+    let mut rng = Rng::new(seed);
+
+    for case in 0..cases {
+        let input = generate(&mut rng);
+        let Err(mut failure) = property(&input) else {
+            continue;
+        };
+
+        let mut smallest = input;
+        while let Some((smaller, why)) = shrink(&smallest)
+            .into_iter()
+            .find_map(|candidate| property(&candidate).err().map(|why| (candidate, why)))
+        {
+            smallest = smaller;
+            failure = why;
+        }
+
+        panic!(
+            "{}: case {} with AOC_SEED={} failed: {}\nminimized input:\n{:#?}",
+            name, case, seed, failure, smallest
+        );
+    }
+}
+
+/// Asserts-style helper for properties: `Ok` if the values match.
+pub fn expect_eq<T: PartialEq + Debug>(actual: T, expected: T) -> Result<(), String> {
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "solver gave {:?}, reference gave {:?}",
+            actual, expected
+        ))
+    }
+}
+
+/// Smaller versions of a list: halves, then each element removed in turn.
+pub fn shrink_vec<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    let mut out = Vec::new();
+    if items.len() > 1 {
+        let half = items.len() / 2;
+        out.push(items[..half].to_vec());
+        out.push(items[half..].to_vec());
+    }
+    for i in 0..items.len() {
+        let mut fewer = items.to_vec();
+        fewer.remove(i);
+        out.push(fewer);
+    }
+    out
+}
+
+/// Smaller versions of a list with one element replaced by each of its own shrinks.
+pub fn shrink_each<T: Clone>(items: &[T], shrink: impl Fn(&T) -> Vec<T>) -> Vec<Vec<T>> {
+    let mut out = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        for smaller in shrink(item) {
+            let mut changed = items.to_vec();
+            changed[i] = smaller;
+            out.push(changed);
+        }
+    }
+    out
+}
+
+/// Values between `0` and `n`, closest to `0` first.
+pub fn shrink_u64(n: u64) -> Vec<u64> {
+    let mut out = Vec::new();
+    let mut step = n;
+    while step > 0 {
+        out.push(n - step);
+        step /= 2;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ranges_stay_in_bounds() {
+        let mut rng = Rng::new(1);
+        for _ in 0..1000 {
+            let v = rng.range(3, 7);
+            assert!((3..=7).contains(&v));
+        }
+        assert_eq!(Rng::new(5).next_u64(), Rng::new(5).next_u64());
+    }
+
+    #[test]
+    fn failures_are_minimized() {
+        let result = std::panic::catch_unwind(|| {
+            check(
+                "small",
+                100,
+                |rng| {
+                    (0..rng.usize(5, 20))
+                        .map(|_| rng.range(0, 100))
+                        .collect::<Vec<_>>()
+                },
+                |v| {
+                    let mut out = shrink_vec(v);
+                    out.extend(shrink_each(v, |&n| shrink_u64(n)));
+                    out
+                },
+                |v| expect_eq(v.iter().all(|&n| n < 50), true),
+            )
+        });
+        let message = *result.unwrap_err().downcast::<String>().unwrap();
+        assert!(
+            message.contains("minimized input:\n[\n    50,\n]"),
+            "{}",
+            message
+        );
+    }
+}
@@ -0,0 +1,225 @@
+//! Step events from the solvers, written as JSON lines for `aoc run --trace`.
+//!
+//! Solvers report steps with [`trace!`](crate::trace!). Until a sink is [`install`]ed it expands
+//! to a single relaxed atomic load and its fields are never evaluated, so tracing costs nothing
+//! measurable in normal runs. Each event is one line such as
+//! `{"day":1,"event":"turn","dir":"L","dist":68,"pos":82}`.
+
+use std::io::{self, Write};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::Pos;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static SINK: Mutex<Option<Sink>> = Mutex::new(None);
+
+struct Sink {
+    out: Box<dyn Write + Send>,
+    /// The first write error, reported by [`finish`].
+    error: Option<io::Error>,
+}
+
+/// Sends every following event to `out`.
+pub fn install(out: Box<dyn Write + Send>) {
+    *SINK.lock().unwrap() = Some(Sink { out, error: None });
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Stops tracing and flushes the sink, reporting the first error writing to it.
+pub fn finish() -> io::Result<()> {
+    ENABLED.store(false, Ordering::Relaxed);
+    match SINK.lock().unwrap().take() {
+        Some(Sink { error: Some(e), .. }) => Err(e),
+        Some(mut sink) => sink.out.flush(),
+        None => Ok(()),
+    }
+}
+
+#[inline]
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Writes one event; use [`trace!`](crate::trace!) rather than calling this directly.
+#[doc(hidden)]
+pub fn emit(day: u8, event: &str, fields: &[(&str, &dyn ToJson)]) {
+    let mut line = String::new();
+    line.push_str("{\"day\":");
+    day.write_json(&mut line);
+    line.push_str(",\"event\":");
+    event.write_json(&mut line);
+    for (name, value) in fields {
+        line.push(',');
+        name.write_json(&mut line);
+        line.push(':');
+        value.write_json(&mut line);
+    }
+    line.push_str("}\n");
+
+    if let Some(sink) = SINK.lock().unwrap().as_mut()
+        && sink.error.is_none()
+        && let Err(e) = sink.out.write_all(line.as_bytes())
+    {
+        sink.error = Some(e);
+    }
+}
+
+/// Records a step: `trace!(day, "event", name = value, ...)`. The values, anything
+/// implementing [`ToJson`], are only evaluated while tracing is enabled.
+#[macro_export]
+macro_rules! trace {
+    ($day:expr, $event:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        if $crate::trace::enabled() {
+            $crate::trace::emit(
+                $day,
+                $event,
+                &[$((stringify!($name), &$value as &dyn $crate::trace::ToJson)),*],
+            );
+        }
+    };
+}
+
+/// A value that can appear in a trace event.
+pub trait ToJson {
+    fn write_json(&self, out: &mut String);
+}
+
+macro_rules! json_number {
+    ($($t:ty),*) => {$(
+        impl ToJson for $t {
+            fn write_json(&self, out: &mut String) {
+                out.push_str(&self.to_string());
+            }
+        }
+    )*};
+}
+
+json_number!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool
+);
+
+impl ToJson for str {
+    fn write_json(&self, out: &mut String) {
+        out.push('"');
+        for c in self.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+}
+
+impl ToJson for String {
+    fn write_json(&self, out: &mut String) {
+        self.as_str().write_json(out);
+    }
+}
+
+impl ToJson for char {
+    fn write_json(&self, out: &mut String) {
+        self.encode_utf8(&mut [0; 4]).write_json(out);
+    }
+}
+
+impl<T: ToJson + ?Sized> ToJson for &T {
+    fn write_json(&self, out: &mut String) {
+        (**self).write_json(out);
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn write_json(&self, out: &mut String) {
+        out.push('[');
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            item.write_json(out);
+        }
+        out.push(']');
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn write_json(&self, out: &mut String) {
+        self.as_slice().write_json(out);
+    }
+}
+
+impl<A: ToJson, B: ToJson> ToJson for (A, B) {
+    fn write_json(&self, out: &mut String) {
+        out.push('[');
+        self.0.write_json(out);
+        out.push(',');
+        self.1.write_json(out);
+        out.push(']');
+    }
+}
+
+/// `[row, col]`.
+impl ToJson for Pos {
+    fn write_json(&self, out: &mut String) {
+        (self.row, self.col).write_json(out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+
+    /// A sink the test can read back after tracing has finished.
+    #[derive(Clone, Default)]
+    struct Shared(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Shared {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn writes_json_lines_only_while_enabled() {
+        let evaluated = std::cell::Cell::new(0);
+        let value = || {
+            evaluated.set(evaluated.get() + 1);
+            7
+        };
+
+        crate::trace!(1, "off", n = value());
+        assert_eq!(evaluated.get(), 0);
+
+        let out = Shared::default();
+        install(Box::new(out.clone()));
+        crate::trace!(
+            2,
+            "step",
+            n = value(),
+            cells = vec![Pos::new(0, 1)],
+            range = (1u128, u128::MAX),
+            op = '*',
+            text = "a \"b\"\n",
+        );
+        finish().unwrap();
+        crate::trace!(3, "off", n = value());
+
+        assert_eq!(evaluated.get(), 1);
+        assert_eq!(
+            String::from_utf8(out.0.lock().unwrap().clone()).unwrap(),
+            "{\"day\":2,\"event\":\"step\",\"n\":7,\"cells\":[[0,1]],\
+             \"range\":[1,340282366920938463463374607431768211455],\"op\":\"*\",\
+             \"text\":\"a \\\"b\\\"\\n\"}\n"
+        );
+    }
+}